@@ -1,5 +1,8 @@
 use crate::msrvs::Msrv;
-use crate::types::{DisallowedPath, MacroMatcher, MatchLintBehaviour, PubUnderscoreFieldsBehaviour, Rename};
+use crate::types::{
+    ClippyLintLevel, DisallowedPath, MacroMatcher, MatchLintBehaviour, MixedWildcardImportsStyle,
+    PubUnderscoreFieldsBehaviour, Rename,
+};
 use crate::ClippyConfiguration;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
@@ -40,6 +43,7 @@
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
 const DEFAULT_ALLOWED_PREFIXES: &[&str] = &["to", "as", "into", "from", "try_into", "try_from"];
+const DEFAULT_GENERATED_FILE_PATTERNS: &[&str] = &["generated", "bindings", "ffi", "sys"];
 const DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS: &[&str] =
     &["core::convert::From", "core::convert::TryFrom", "core::str::FromStr"];
 
@@ -640,6 +644,54 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Whether to also emit warnings for unsafe blocks with metavariable expansions in **private** macros.
     (warn_unsafe_macro_metavars_in_private_macros: bool = false),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// Whether to allow wildcard imports from crates of the same workspace, i.e. from the current crate
+    /// and the crates located below the root of the workspace when checked with Cargo. Imports from
+    /// registry and git dependencies are still linted.
+    (allow_same_workspace_glob: bool = false),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// The minimum number of lines a file needs to have for wildcard imports in it to be linted.
+    /// The default of `0` lints files of any length.
+    (wildcard_imports_min_file_lines: u64 = 0),
+    /// Lint: LARGE_PUB_WILDCARD_IMPORTS.
+    ///
+    /// The maximum number of items a public wildcard re-export may add to the public API.
+    (pub_wildcard_max_reexports: u64 = 20),
+    /// Lint: MIXED_WILDCARD_IMPORTS.
+    ///
+    /// Which style of import to keep when a module imports from another module both with a wildcard and by name.
+    /// With `"prefer-explicit"` the wildcard imports are linted, with `"prefer-glob"` the imports by name.
+    (mixed_wildcard_imports_style: MixedWildcardImportsStyle = MixedWildcardImportsStyle::PreferExplicit),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// Overrides the default level of `wildcard_imports`, which otherwise is only enabled as part of
+    /// `clippy::pedantic`. One of `"allow"`, `"warn"` or `"deny"`. Lint attributes and command line
+    /// flags still take precedence.
+    #[default_text = ""]
+    (wildcard_imports_severity: Option<ClippyLintLevel> = None),
+    /// Lint: WILDCARD_IMPORTS_REPORT.
+    ///
+    /// Whether to report all wildcard imports of the crate, with the names used through them, in a single
+    /// diagnostic that tooling can use to expand them.
+    (wildcard_imports_report: bool = false),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// Words that mark a file as generated when its name contains one of them, like `ffi` in `ffi_bindings.rs`.
+    /// Wildcard imports in such files are not linted. Use `".."` as part of the list to append to the default
+    /// patterns instead of replacing them.
+    (wildcard_imports_generated_file_patterns: Vec<String> = DEFAULT_GENERATED_FILE_PATTERNS.iter().map(ToString::to_string).collect()),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// The text of a line comment that marks the wildcard import directly below it as intentional, like
+    /// `// clippy:wildcard-ok`. Such wildcard imports are not linted. An empty string disables the marker.
+    (wildcard_imports_marker_comment: String = "clippy:wildcard-ok".to_string()),
+    /// Lint: WILDCARD_IMPORTS.
+    ///
+    /// Whether to only lint wildcard imports in modules that are reachable from outside the crate.
+    /// Wildcard imports in private modules don't affect the public API of the crate.
+    (wildcard_imports_only_public_modules: bool = false),
 }
 
 /// Search for the configuration file.
@@ -695,12 +747,34 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     }
 }
 
+/// Reads only the `wildcard-imports-severity` option from the configuration file. Lint levels have
+/// to be known before the compiler session is created, which is too early for [`Conf::read`].
+///
+/// Any error is ignored here, and reported once the whole configuration is read.
+pub fn read_wildcard_imports_severity(path: &io::Result<(Option<PathBuf>, Vec<String>)>) -> Option<ClippyLintLevel> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "kebab-case")]
+    struct Severity {
+        wildcard_imports_severity: Option<ClippyLintLevel>,
+    }
+
+    let Ok((Some(path), _)) = path else {
+        return None;
+    };
+    let file = fs::read_to_string(path).ok()?;
+    toml::from_str::<Severity>(&file).ok()?.wildcard_imports_severity
+}
+
 fn deserialize(file: &SourceFile) -> TryConf {
     match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
             extend_vec_if_indicator_present(&mut conf.conf.allowed_prefixes, DEFAULT_ALLOWED_PREFIXES);
+            extend_vec_if_indicator_present(
+                &mut conf.conf.wildcard_imports_generated_file_patterns,
+                DEFAULT_GENERATED_FILE_PATTERNS,
+            );
             extend_vec_if_indicator_present(
                 &mut conf.conf.allow_renamed_params_for,
                 DEFAULT_ALLOWED_TRAITS_WITH_RENAMED_PARAMS,
@@ -27,5 +27,5 @@
 pub mod msrvs;
 pub mod types;
 
-pub use conf::{get_configuration_metadata, lookup_conf_file, Conf};
+pub use conf::{get_configuration_metadata, lookup_conf_file, read_wildcard_imports_severity, Conf};
 pub use metadata::ClippyConfiguration;
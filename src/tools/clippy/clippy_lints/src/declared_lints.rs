@@ -749,8 +749,33 @@
     crate::visibility::NEEDLESS_PUB_SELF_INFO,
     crate::visibility::PUB_WITHOUT_SHORTHAND_INFO,
     crate::visibility::PUB_WITH_SHORTHAND_INFO,
+    crate::wildcard_imports::BUILTIN_MACRO_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::CASE_COLLIDING_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::CFG_DEPENDENT_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::CRATE_API_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::DERIVE_COLLIDING_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::DUPLICATE_PATH_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::EMPTY_WILDCARD_IMPORT_INFO,
     crate::wildcard_imports::ENUM_GLOB_USE_INFO,
+    crate::wildcard_imports::EXTENSION_TRAIT_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::KEYWORD_LIKE_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::LARGE_PUB_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::MASKED_UNUSED_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::MIXED_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::PARAM_SHADOWED_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::PRELUDE_CANDIDATE_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::PRIVATE_INTERFACE_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::QUALIFIED_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::REPEATED_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::SELF_AND_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::SHORTER_PATH_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::SINGLE_FUNCTION_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::TRAIT_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::TRANSITIVE_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::TYPE_AND_VALUE_WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::UNSTABLE_WILDCARD_IMPORTS_INFO,
     crate::wildcard_imports::WILDCARD_IMPORTS_INFO,
+    crate::wildcard_imports::WILDCARD_IMPORTS_REPORT_INFO,
     crate::write::PRINTLN_EMPTY_STRING_INFO,
     crate::write::PRINT_LITERAL_INFO,
     crate::write::PRINT_STDERR_INFO,
@@ -542,6 +542,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         allow_private_module_inception,
         allow_unwrap_in_tests,
         allow_useless_vec_in_tests,
+        allow_same_workspace_glob,
         ref allowed_dotfiles,
         ref allowed_idents_below_min_chars,
         ref allowed_scripts,
@@ -575,10 +576,13 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         max_struct_bools,
         max_suggested_slice_pattern_length,
         max_trait_bounds,
+        wildcard_imports_min_file_lines,
         min_ident_chars_threshold,
         missing_docs_in_crate_items,
+        mixed_wildcard_imports_style,
         ref msrv,
         pass_by_value_size_limit,
+        pub_wildcard_max_reexports,
         semicolon_inside_block_ignore_singleline,
         semicolon_outside_block_ignore_multiline,
         single_char_binding_names_threshold,
@@ -597,6 +601,10 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         vec_box_size_threshold,
         verbose_bit_mask_threshold,
         warn_on_all_wildcard_imports,
+        wildcard_imports_report,
+        ref wildcard_imports_generated_file_patterns,
+        ref wildcard_imports_marker_comment,
+        wildcard_imports_only_public_modules,
         check_private_items,
         pub_underscore_fields_behavior,
         ref allowed_duplicate_crates,
@@ -606,6 +614,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
 
         blacklisted_names: _,
         cyclomatic_complexity_threshold: _,
+        // Applied by the driver, see `clippy_config::read_wildcard_imports_severity`.
+        wildcard_imports_severity: _,
         warn_unsafe_macro_metavars_in_private_macros,
     } = *conf;
     let msrv = || msrv.clone();
@@ -920,6 +930,14 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
         Box::new(wildcard_imports::WildcardImports::new(
             warn_on_all_wildcard_imports,
             allowed_wildcard_imports.clone(),
+            allow_same_workspace_glob,
+            wildcard_imports_min_file_lines,
+            pub_wildcard_max_reexports,
+            mixed_wildcard_imports_style,
+            wildcard_imports_report,
+            wildcard_imports_generated_file_patterns.clone(),
+            wildcard_imports_marker_comment.clone(),
+            wildcard_imports_only_public_modules,
         ))
     });
     store.register_late_pass(|_| Box::<redundant_pub_crate::RedundantPubCrate>::default());
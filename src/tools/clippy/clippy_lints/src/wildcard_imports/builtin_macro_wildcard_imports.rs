@@ -0,0 +1,78 @@
+use super::{module_children, BUILTIN_MACRO_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::MacroKind;
+
+/// Function-like macros that are available everywhere through the standard library prelude.
+const BUILTIN_MACROS: &[&str] = &[
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "cfg",
+    "column",
+    "compile_error",
+    "concat",
+    "dbg",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "env",
+    "eprint",
+    "eprintln",
+    "file",
+    "format",
+    "format_args",
+    "include",
+    "include_bytes",
+    "include_str",
+    "line",
+    "matches",
+    "module_path",
+    "option_env",
+    "panic",
+    "print",
+    "println",
+    "stringify",
+    "todo",
+    "unimplemented",
+    "unreachable",
+    "vec",
+    "write",
+    "writeln",
+];
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let mut macros = module_children(cx, def_id)
+        .iter()
+        .filter(|child| {
+            matches!(child.res, Res::Def(DefKind::Macro(MacroKind::Bang), _))
+                && child.vis.is_accessible_from(module.to_def_id(), cx.tcx)
+                && BUILTIN_MACROS.contains(&child.ident.as_str())
+        })
+        .map(|child| format!("`{}!`", child.ident))
+        .collect::<Vec<_>>();
+    if macros.is_empty() {
+        return;
+    }
+    macros.sort_unstable();
+
+    span_lint_and_then(
+        cx,
+        BUILTIN_MACRO_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in a macro with the same name as a built-in macro",
+        |diag| {
+            diag.note(format!(
+                "using {} in this module is ambiguous between the imported and the built-in macro",
+                macros.join(", ")
+            ));
+        },
+    );
+}
@@ -0,0 +1,48 @@
+use super::{module_children, CASE_COLLIDING_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let mut names = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        .map(|child| (child.ident.as_str().to_lowercase(), child.ident.to_string()))
+        .collect::<Vec<_>>();
+    // A unit or tuple struct shows up once per namespace, with the same name both times.
+    names.sort_unstable();
+    names.dedup();
+
+    let colliding = names
+        .chunk_by(|(a, _), (b, _)| a == b)
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            group
+                .iter()
+                .map(|(_, name)| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .collect::<Vec<_>>();
+    if colliding.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        CASE_COLLIDING_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in names that differ only by case",
+        |diag| {
+            for group in colliding {
+                diag.note(format!("{group} differ only by case"));
+            }
+        },
+    );
+}
@@ -0,0 +1,73 @@
+use super::{module_children, CFG_DEPENDENT_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_ast::{MetaItem, NestedMetaItem};
+use rustc_ast_pretty::pprust;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    // `#[cfg]` attributes that hold are kept on the item, so the exported items defined in the
+    // module itself tell which of them only exist in some configurations.
+    let included = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        .filter_map(|child| Some((child.ident, child.res.opt_def_id()?)))
+        .filter(|&(_, child_id)| cx.tcx.opt_parent(child_id) == Some(def_id))
+        .flat_map(|(ident, child_id)| {
+            cx.tcx
+                .get_attrs(child_id, sym::cfg)
+                .filter_map(|attr| attr.meta())
+                .filter(mentions_feature)
+                .map(move |cfg| format!("`{ident}` is only exported with `{}`", cfg_to_string(cfg)))
+        });
+    // Items whose `#[cfg]` doesn't hold are gone by now, but rustc remembers their names.
+    let stripped = cx
+        .tcx
+        .stripped_cfg_items(def_id.krate)
+        .iter()
+        .filter(|stripped| stripped.parent_module == def_id && mentions_feature(&stripped.cfg))
+        .map(|stripped| {
+            format!(
+                "`{}` is left out, as `{}` doesn't hold",
+                stripped.name,
+                cfg_to_string(stripped.cfg.clone())
+            )
+        });
+    let mut notes = included.chain(stripped).collect::<Vec<_>>();
+    if notes.is_empty() {
+        return;
+    }
+    notes.sort_unstable();
+    notes.dedup();
+
+    span_lint_and_then(
+        cx,
+        CFG_DEPENDENT_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import from a module whose exports depend on enabled features",
+        |diag| {
+            for note in notes {
+                diag.note(note);
+            }
+        },
+    );
+}
+
+/// Checks if a `cfg` predicate depends on a `feature`, possibly nested in `all`, `any` or `not`.
+fn mentions_feature(meta: &MetaItem) -> bool {
+    meta.has_name(sym::feature)
+        || meta
+            .meta_item_list()
+            .is_some_and(|list| list.iter().filter_map(NestedMetaItem::meta_item).any(mentions_feature))
+}
+
+fn cfg_to_string(cfg: MetaItem) -> String {
+    pprust::meta_list_item_to_string(&NestedMetaItem::MetaItem(cfg))
+}
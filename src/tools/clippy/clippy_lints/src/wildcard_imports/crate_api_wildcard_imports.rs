@@ -0,0 +1,44 @@
+use super::CRATE_API_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use rustc_hir::def_id::CRATE_DEF_ID;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_session::config::CrateType;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    if !cx.tcx.visibility(item.owner_id.def_id).is_public()
+        || cx.tcx.parent_module_from_def_id(item.owner_id.def_id).to_local_def_id() != CRATE_DEF_ID
+        || cx
+            .tcx
+            .crate_types()
+            .iter()
+            .any(|t| matches!(t, CrateType::Executable | CrateType::ProcMacro))
+    {
+        return;
+    }
+    // Anything else that is public at the crate root, like a `pub mod` or a second re-export, means
+    // the API is at least partly spelled out.
+    let is_sole_public_item = cx
+        .tcx
+        .hir()
+        .root_module()
+        .item_ids
+        .iter()
+        .all(|id| id.owner_id == item.owner_id || !cx.tcx.visibility(id.owner_id.def_id).is_public());
+    if !is_sole_public_item {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        CRATE_API_WILDCARD_IMPORTS,
+        use_path.span,
+        "the public API of this crate is defined by a single wildcard re-export",
+        None,
+        format!(
+            "consider re-exporting the items of `{}` by name",
+            snippet(cx, use_path.span, "..")
+        ),
+    );
+}
@@ -0,0 +1,54 @@
+use super::{module_children, DERIVE_COLLIDING_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, ItemKind, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::symbol::kw;
+use rustc_span::{ExpnKind, MacroKind};
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let children = module_children(cx, def_id);
+    // Derive macros run before lowering to HIR, so the items they generate are already part of the
+    // module, with a span pointing into the expansion of the derive.
+    let mut notes = cx
+        .tcx
+        .hir()
+        .module_items(module)
+        .map(|id| cx.tcx.hir().item(id))
+        .filter(|generated| {
+            !matches!(generated.kind, ItemKind::Impl(_) | ItemKind::Use(..)) && generated.ident.name != kw::Empty
+        })
+        .filter_map(|generated| match generated.span.ctxt().outer_expn_data().kind {
+            ExpnKind::Macro(MacroKind::Derive, derive) => Some((generated.ident, derive)),
+            _ => None,
+        })
+        .filter(|(ident, _)| {
+            children
+                .iter()
+                .any(|child| child.ident.name == ident.name && child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        })
+        .map(|(ident, derive)| format!("`{ident}` is also generated by `#[derive({derive})]`"))
+        .collect::<Vec<_>>();
+    if notes.is_empty() {
+        return;
+    }
+    notes.sort_unstable();
+    notes.dedup();
+
+    span_lint_and_then(
+        cx,
+        DERIVE_COLLIDING_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in names that a derive macro generates items for",
+        |diag| {
+            for note in notes {
+                diag.note(note);
+            }
+        },
+    );
+}
@@ -0,0 +1,57 @@
+use super::{module_children, DUPLICATE_PATH_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    // Re-exporting another crate's items is the usual way to build a facade, so only look at
+    // modules of the current crate.
+    let Some(local_id) = def_id.as_local() else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    if !cx.tcx.visibility(item.owner_id.def_id).is_public()
+        || !cx.effective_visibilities.is_exported(module.to_local_def_id())
+        || !cx.effective_visibilities.is_exported(local_id)
+    {
+        return;
+    }
+
+    let mut names = module_children(cx, def_id)
+        .iter()
+        .filter(|child| {
+            child.vis.is_public()
+                && child
+                    .res
+                    .opt_def_id()
+                    .and_then(|def_id| def_id.as_local())
+                    .is_some_and(|def_id| cx.effective_visibilities.is_exported(def_id))
+        })
+        .map(|child| format!("`{}`", child.ident))
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        return;
+    }
+    // A unit or tuple struct shows up once per namespace, with the same name both times.
+    names.sort_unstable();
+    names.dedup();
+
+    span_lint_and_then(
+        cx,
+        DUPLICATE_PATH_WILDCARD_IMPORTS,
+        use_path.span,
+        "public wildcard re-export makes items reachable through more than one public path",
+        |diag| {
+            diag.note(format!(
+                "these items are also public at `{}`: {}",
+                cx.tcx.def_path_str(def_id),
+                names.join(", ")
+            ));
+        },
+    );
+}
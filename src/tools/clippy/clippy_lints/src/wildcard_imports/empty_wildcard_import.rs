@@ -0,0 +1,42 @@
+use super::{module_children, EMPTY_WILDCARD_IMPORT};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    // The import may bring in items that are only compiled on other targets or with other features.
+    if cx
+        .tcx
+        .stripped_cfg_items(def_id.krate)
+        .iter()
+        .any(|stripped| stripped.parent_module == def_id)
+    {
+        return;
+    }
+    // Private items are still visible to descendant modules, like the ones of `use super::*`.
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    if module_children(cx, def_id)
+        .iter()
+        .any(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+    {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        EMPTY_WILDCARD_IMPORT,
+        use_path.span,
+        format!(
+            "this wildcard import has no effect, as `{}` has no items visible here",
+            snippet(cx, use_path.span, "..")
+        ),
+        None,
+        "remove the import",
+    );
+}
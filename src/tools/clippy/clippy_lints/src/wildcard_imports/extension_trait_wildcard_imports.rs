@@ -0,0 +1,56 @@
+use super::{is_prelude_import, module_children, EXTENSION_TRAIT_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    // Bringing extension traits into scope is what preludes are for.
+    if is_prelude_import(use_path.segments) {
+        return;
+    }
+    // Traits that are only in scope for method resolution are recorded as used by the glob as well,
+    // so a glob whose used names are all traits only serves to make their methods callable.
+    let used = cx.tcx.names_imported_by_glob_use(item.owner_id.def_id);
+    if used.is_empty() {
+        return;
+    }
+    let children = module_children(cx, def_id);
+    let only_traits = used.items().all(|name| {
+        let mut named = children.iter().filter(|child| child.ident.name == *name).peekable();
+        named.peek().is_some()
+            && named.all(|child| matches!(child.res, Res::Def(DefKind::Trait | DefKind::TraitAlias, _)))
+    });
+    if !only_traits {
+        return;
+    }
+    let mut traits = used.items().map(ToString::to_string).into_sorted_stable_ord();
+    let names = if traits.len() == 1 {
+        traits.pop().unwrap()
+    } else {
+        format!("{{{}}}", traits.join(", "))
+    };
+    let path = snippet(cx, use_path.span, "..");
+    let import = if path.is_empty() {
+        names
+    } else {
+        format!("{path}::{names}")
+    };
+
+    span_lint_and_then(
+        cx,
+        EXTENSION_TRAIT_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import is only used to bring extension trait methods into scope",
+        |diag| {
+            diag.help(format!(
+                "consider `use {import};` instead, with a comment on the methods it is needed for"
+            ));
+        },
+    );
+}
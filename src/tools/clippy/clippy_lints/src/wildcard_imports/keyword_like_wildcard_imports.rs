@@ -0,0 +1,61 @@
+use super::{module_children, KEYWORD_LIKE_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::symbol::{kw, Symbol};
+
+/// Keywords which only have a special meaning in some positions, and are ordinary identifiers
+/// everywhere else.
+const CONTEXTUAL_KEYWORDS: &[Symbol] = &[
+    kw::Auto,
+    kw::Catch,
+    kw::Default,
+    kw::Gen,
+    kw::MacroRules,
+    kw::Raw,
+    kw::Reuse,
+    kw::Safe,
+    kw::Union,
+    kw::Yeet,
+];
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let mut notes = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        .filter_map(|child| {
+            // `Ident`'s `Display` already adds the `r#` prefix where it is needed.
+            if child.ident.is_raw_guess() {
+                Some(format!("`{}` is a raw identifier", child.ident))
+            } else if CONTEXTUAL_KEYWORDS.contains(&child.ident.name) {
+                Some(format!("`{}` is a contextual keyword", child.ident))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    if notes.is_empty() {
+        return;
+    }
+    // A unit or tuple struct shows up once per namespace, with the same name both times.
+    notes.sort_unstable();
+    notes.dedup();
+
+    span_lint_and_then(
+        cx,
+        KEYWORD_LIKE_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in names that are easy to mistake for keywords",
+        |diag| {
+            for note in notes {
+                diag.note(note);
+            }
+        },
+    );
+}
@@ -0,0 +1,42 @@
+use super::{module_children, LARGE_PUB_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::symbol::kw;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>, max_reexports: u64) {
+    if !cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+        return;
+    }
+    // Glob imports always have a single resolution.
+    let Some(def_id) = use_path.res[0].opt_def_id() else {
+        return;
+    };
+    // A name can be re-exported in more than one namespace, like a unit struct and its constructor,
+    // but it only shows up once in the documentation.
+    let names = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_public() && child.ident.name != kw::Underscore)
+        .map(|child| child.ident.name)
+        .collect::<FxHashSet<_>>();
+    if names.len() as u64 <= max_reexports {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        LARGE_PUB_WILDCARD_IMPORTS,
+        use_path.span,
+        format!(
+            "this wildcard re-export adds {} items to the public API, exceeding the maximum of {max_reexports}",
+            names.len()
+        ),
+        None,
+        format!(
+            "consider re-exporting a curated list of the items of `{}` by name",
+            snippet(cx, use_path.span, "..")
+        ),
+    );
+}
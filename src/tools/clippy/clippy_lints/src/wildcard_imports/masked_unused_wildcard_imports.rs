@@ -0,0 +1,44 @@
+use super::MASKED_UNUSED_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_ast::Attribute;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    let Some(allow) = cx
+        .tcx
+        .hir()
+        .attrs(item.hir_id())
+        .iter()
+        .find(|attr| allows_unused_imports(attr))
+    else {
+        return;
+    };
+    if !cx.tcx.names_imported_by_glob_use(item.owner_id.def_id).is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        MASKED_UNUSED_WILDCARD_IMPORTS,
+        use_path.span,
+        "this wildcard import is unused, but an `#[allow]` hides the warning",
+        |diag| {
+            diag.span_note(allow.span, "`unused_imports` is allowed here");
+            diag.help("remove the import together with the attribute");
+        },
+    );
+}
+
+/// Whether `attr` is an `#[allow(..)]` that covers `unused_imports`, directly or through the `unused`
+/// lint group.
+fn allows_unused_imports(attr: &Attribute) -> bool {
+    attr.has_name(sym::allow)
+        && attr.meta_item_list().is_some_and(|lints| {
+            lints.iter().any(|lint| {
+                lint.ident()
+                    .is_some_and(|ident| ident.name == sym::unused_imports || ident.as_str() == "unused")
+            })
+        })
+}
@@ -0,0 +1,79 @@
+use super::{is_prelude_import, MIXED_WILDCARD_IMPORTS};
+use clippy_config::types::MixedWildcardImportsStyle;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{HirId, ItemKind, Mod, UseKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+#[derive(Default)]
+struct ModuleImports {
+    globs: Vec<(HirId, Span)>,
+    explicit: Vec<(HirId, Span)>,
+}
+
+pub(super) fn check_mod(cx: &LateContext<'_>, module: &Mod<'_>, style: MixedWildcardImportsStyle) {
+    // Group the imports of the module by the module they import from, like `std::fmt` for both
+    // `use std::fmt::*` and `use std::fmt::Debug`, so that only names the wildcard brings in as
+    // well are counted.
+    let mut sources = FxIndexMap::<DefId, ModuleImports>::default();
+    for item in module.item_ids.iter().map(|&id| cx.tcx.hir().item(id)) {
+        if let ItemKind::Use(use_path, kind) = item.kind
+            && kind != UseKind::ListStem
+            && !item.span.from_expansion()
+        {
+            if kind == UseKind::Glob {
+                // Glob imports always have a single resolution. Preludes are meant to be imported
+                // with a wildcard, next to whatever else is needed.
+                if let Some(source) = use_path.res[0].opt_def_id()
+                    && !is_prelude_import(use_path.segments)
+                {
+                    sources
+                        .entry(source)
+                        .or_default()
+                        .globs
+                        .push((item.hir_id(), item.span));
+                }
+            } else if let [.., parent, _] = use_path.segments
+                && let Some(source) = parent.res.opt_def_id()
+            {
+                sources
+                    .entry(source)
+                    .or_default()
+                    .explicit
+                    .push((item.hir_id(), item.span));
+            }
+        }
+    }
+
+    for (source, imports) in sources {
+        if imports.globs.is_empty() || imports.explicit.is_empty() {
+            continue;
+        }
+        let name = cx.tcx.def_path_str(source);
+        let (linted, others, msg, note, help) = match style {
+            MixedWildcardImportsStyle::PreferExplicit => (
+                imports.globs,
+                imports.explicit,
+                format!("wildcard import from `{name}`, which is also imported by name"),
+                format!("`{name}` is also imported by name here"),
+                format!("consider importing the items of `{name}` by name"),
+            ),
+            MixedWildcardImportsStyle::PreferGlob => (
+                imports.explicit,
+                imports.globs,
+                format!("import by name from `{name}`, which is also imported with a wildcard"),
+                format!("`{name}` is also imported with a wildcard here"),
+                format!("consider importing the items of `{name}` with a wildcard"),
+            ),
+        };
+        let others = others.into_iter().map(|(_, span)| span).collect::<Vec<_>>();
+        for (hir_id, span) in linted {
+            span_lint_hir_and_then(cx, MIXED_WILDCARD_IMPORTS, hir_id, span, msg.clone(), |diag| {
+                diag.span_note(others.clone(), note.clone());
+                diag.help(help.clone());
+            });
+        }
+    }
+}
@@ -0,0 +1,1679 @@
+mod builtin_macro_wildcard_imports;
+mod case_colliding_wildcard_imports;
+mod cfg_dependent_wildcard_imports;
+mod crate_api_wildcard_imports;
+mod derive_colliding_wildcard_imports;
+mod duplicate_path_wildcard_imports;
+mod empty_wildcard_import;
+mod extension_trait_wildcard_imports;
+mod keyword_like_wildcard_imports;
+mod large_pub_wildcard_imports;
+mod masked_unused_wildcard_imports;
+mod mixed_wildcard_imports;
+mod param_shadowed_wildcard_imports;
+mod prelude_candidate_wildcard_imports;
+mod private_interface_wildcard_imports;
+mod qualified_wildcard_imports;
+mod repeated_wildcard_imports;
+mod self_and_wildcard_imports;
+mod shorter_path_wildcard_imports;
+mod single_function_wildcard_imports;
+mod trait_wildcard_imports;
+mod transitive_wildcard_imports;
+mod type_and_value_wildcard_imports;
+mod unstable_wildcard_imports;
+mod wildcard_imports_report;
+
+use clippy_config::types::MixedWildcardImportsStyle;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet, snippet_with_applicability};
+use clippy_utils::{is_test_module_or_function, match_def_path, paths};
+use itertools::Itertools;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::unord::UnordSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
+use rustc_hir::definitions::DefPathData;
+use rustc_hir::{HirId, Item, ItemKind, Mod, Node, Path, PathSegment, UseKind, UsePath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::metadata::ModChild;
+use rustc_middle::ty;
+use rustc_session::impl_lint_pass;
+use rustc_span::symbol::{kw, Symbol};
+use rustc_span::{sym, BytePos, ExpnKind, FileName, MacroKind, RealFileName, Span};
+use std::env;
+use std::path::PathBuf;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `use Enum::*`.
+    ///
+    /// ### Why is this bad?
+    /// It is usually better style to use the prefixed name of
+    /// an enumeration variant, rather than importing variants.
+    ///
+    /// ### Known problems
+    /// Old-style enumerations that prefix the variants are
+    /// still around.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::cmp::Ordering::*;
+    ///
+    /// # fn foo(_: std::cmp::Ordering) {}
+    /// foo(Less);
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// use std::cmp::Ordering;
+    ///
+    /// # fn foo(_: Ordering) {}
+    /// foo(Ordering::Less)
+    /// ```
+    #[clippy::version = "pre 1.29.0"]
+    pub ENUM_GLOB_USE,
+    pedantic,
+    "use items that import all variants of an enum"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*`.
+    ///
+    /// ### Why is this bad?
+    /// wildcard imports can pollute the namespace. This is especially bad if
+    /// you try to import something through a wildcard, that already has been imported by name from
+    /// a different source:
+    ///
+    /// ```rust,ignore
+    /// use crate1::foo; // Imports a function named foo
+    /// use crate2::*; // Has a function named foo
+    ///
+    /// foo(); // Calls crate1::foo
+    /// ```
+    ///
+    /// This can lead to confusing error messages at best and to unexpected behavior at worst.
+    ///
+    /// ### Exceptions
+    /// Wildcard imports are allowed from modules that their name contains `prelude`. Many crates
+    /// (including the standard library) provide modules named "prelude" specifically designed
+    /// for wildcard import. The prelude of the standard library itself, like `std::prelude::v1`,
+    /// is only allowed in `#[no_implicit_prelude]` modules, as every other module already imports
+    /// it implicitly.
+    ///
+    /// `use super::*` is allowed in test modules. This is defined as any module with "test" in the name.
+    ///
+    /// Wildcard imports from crates of the same workspace can be allowed using the
+    /// `allow-same-workspace-glob` configuration flag.
+    ///
+    /// Files shorter than the `wildcard-imports-min-file-lines` configuration value are not linted.
+    ///
+    /// Files that look generated are not linted either, as their imports are not meant to be edited
+    /// by hand. These are the files whose name contains one of the
+    /// `wildcard-imports-generated-file-patterns` as a word, like `ffi` in
+    /// `#[path = "ffi_bindings.rs"] mod ffi;`. By default the patterns are `generated`, `bindings`,
+    /// `ffi` and `sys`.
+    ///
+    /// A wildcard import directly below a `// clippy:wildcard-ok` comment is not linted, which marks
+    /// it as intentional without an `#[allow]` attribute. The marker text is set with the
+    /// `wildcard-imports-marker-comment` configuration value.
+    ///
+    /// With the `wildcard-imports-only-public-modules` configuration flag, wildcard imports are only
+    /// linted in modules that are reachable from outside the crate, whether or not the import itself
+    /// is `pub`.
+    ///
+    /// Wildcard imports from other crates, except the standard library, are allowed in the files of
+    /// the `tests` directory of a package, like `use test_utils::*` in the helper module of a
+    /// `harness = false` test. Any dependency is allowed there, not only dev-dependencies. Tests
+    /// built with the test harness are not linted at all.
+    ///
+    /// Doc-tests are not linted, since a fix can't be applied to the doc comment they come from.
+    ///
+    /// Wildcard imports inside the initializer of a `lazy_static!` or a `once_cell` `Lazy` static
+    /// are allowed, since they are scoped to that initializer. The same goes for wildcard imports
+    /// inside an anonymous `const _: () = { ... };`, which is mostly written by code generators.
+    ///
+    /// These exceptions can be disabled using the `warn-on-all-wildcard-imports` configuration flag.
+    ///
+    /// Wildcard re-exports like `pub use super::*` are never linted, not even with that flag. They are
+    /// how a module builds its API, for example by re-exporting its parent from a `pub mod`.
+    ///
+    /// ### Known problems
+    /// If macros are imported through the wildcard, this macro is not included
+    /// by the suggestion and has to be added by hand.
+    ///
+    /// Applying the suggestion when explicit imports of the things imported with a glob import
+    /// exist, may result in `unused_imports` warnings.
+    ///
+    /// Traits can't contain `use` items yet. If they could, a wildcard import directly in a trait
+    /// would be linted like any other, even though the suggestion isn't written with that in mind.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use crate1::*;
+    ///
+    /// foo();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use crate1::foo;
+    ///
+    /// foo();
+    /// ```
+    #[clippy::version = "1.43.0"]
+    pub WILDCARD_IMPORTS,
+    pedantic,
+    "lint `use _::*` statements"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` from modules whose items are mostly referred
+    /// to with a qualified path anyway.
+    ///
+    /// ### Why is this bad?
+    /// If most of the code already spells out `module::Item`, the glob only adds a second way to
+    /// refer to the same items. Importing the module itself, optionally under a shorter alias,
+    /// makes the qualified style consistent.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use some::long::path::*;
+    ///
+    /// let a = some::long::path::Name;
+    /// let b = some::long::path::Other;
+    /// let c = Third;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use some::long::path as p;
+    ///
+    /// let a = p::Name;
+    /// let b = p::Other;
+    /// let c = p::Third;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub QUALIFIED_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports from a module whose items are mostly used with a qualified path"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in a type and a value with the same name,
+    /// like a unit or tuple struct together with its constructor.
+    ///
+    /// ### Why is this bad?
+    /// Types and values live in separate namespaces, so a single name can refer to two different
+    /// things. With a glob, neither of them is spelled out at the import, which makes it harder to
+    /// tell which one a given usage refers to.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod shapes {
+    ///     pub struct Circle;
+    /// }
+    ///
+    /// use shapes::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use shapes::Circle;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub TYPE_AND_VALUE_WILDCARD_IMPORTS,
+    pedantic,
+    "wildcard imports bringing in a type and a value with the same name"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for module level wildcard imports `use _::*` whose names are only used inside
+    /// a single function.
+    ///
+    /// ### Why is this bad?
+    /// The glob makes all of the module's items visible to the whole surrounding module, while only
+    /// one function needs them. Moving the import into that function keeps the rest of the module
+    /// free of names it doesn't use.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use std::collections::*;
+    ///
+    /// fn count_unique(words: &[&str]) -> usize {
+    ///     let unique: HashSet<_> = words.iter().collect();
+    ///     unique.len()
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn count_unique(words: &[&str]) -> usize {
+    ///     use std::collections::*;
+    ///
+    ///     let unique: HashSet<_> = words.iter().collect();
+    ///     unique.len()
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SINGLE_FUNCTION_WILDCARD_IMPORTS,
+    pedantic,
+    "wildcard imports whose names are only used inside a single function"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in a macro with the same name as one of
+    /// the standard library's built-in macros, like `vec!` or `println!`.
+    ///
+    /// ### Why is this bad?
+    /// A glob imported macro doesn't simply shadow the built-in one. Both are in scope, so invoking
+    /// the macro is an ambiguity error, which only shows up once the macro is actually used.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod my_macros {
+    ///     macro_rules! vec {
+    ///         () => {};
+    ///     }
+    ///     pub(crate) use vec;
+    /// }
+    ///
+    /// use my_macros::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use my_macros::vec;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub BUILTIN_MACRO_WILDCARD_IMPORTS,
+    style,
+    "wildcard imports bringing in a macro named like a built-in macro"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in several names which only differ by
+    /// case, like a function `foo` and a unit struct `Foo`.
+    ///
+    /// ### Why is this bad?
+    /// Names that only differ by case are easy to mix up when reading code, and with a glob
+    /// neither of them is spelled out at the import to help tell them apart.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod items {
+    ///     pub struct Foo;
+    ///     pub fn foo() {}
+    /// }
+    ///
+    /// use items::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use items::{Foo, foo};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CASE_COLLIDING_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing in names that differ only by case"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public wildcard re-exports `pub use _::*` of public modules of the same crate.
+    ///
+    /// ### Why is this bad?
+    /// Every item of the module becomes reachable through two public paths, the original one and
+    /// the re-export. The documentation then lists the same items in two places, and users of the
+    /// crate can't tell which path is the intended one.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// pub mod shapes {
+    ///     pub struct Circle;
+    /// }
+    ///
+    /// pub use shapes::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// mod shapes {
+    ///     pub struct Circle;
+    /// }
+    ///
+    /// pub use shapes::Circle;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub DUPLICATE_PATH_WILDCARD_IMPORTS,
+    pedantic,
+    "public wildcard re-exports making items reachable through more than one public path"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for library crates whose root module only exports a single wildcard re-export
+    /// `pub use _::*`.
+    ///
+    /// ### Why is this bad?
+    /// The public API of the crate is then whatever happens to be public in the re-exported module.
+    /// Making an item `pub` for use elsewhere in the crate silently adds it to the API, which makes
+    /// it easy to expose, and later break, things that were never meant to be public.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod internal;
+    ///
+    /// pub use internal::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// mod internal;
+    ///
+    /// pub use internal::{Client, Config, Error};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CRATE_API_WILDCARD_IMPORTS,
+    pedantic,
+    "crate roots whose public API is a single wildcard re-export"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public wildcard re-exports `pub use _::*` that add more items to the public API
+    /// than the `pub-wildcard-max-reexports` configuration value allows.
+    ///
+    /// ### Why is this bad?
+    /// Everything that is public in the re-exported module ends up in the documentation of the
+    /// re-exporting one. For a large module, that buries the items users are meant to reach for
+    /// among the ones that just happen to be public.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod internal {
+    ///     pub struct Client;
+    ///     pub struct Config;
+    ///     // ...and dozens of helpers
+    /// }
+    ///
+    /// pub use internal::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub use internal::{Client, Config};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub LARGE_PUB_WILDCARD_IMPORTS,
+    restriction,
+    "public wildcard re-exports adding more items to the public API than configured"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules which import from the same module both with a wildcard `use _::*` and
+    /// by name. Depending on the `mixed-wildcard-imports-style` configuration value, either the
+    /// wildcard imports or the imports by name are linted.
+    ///
+    /// Wildcard imports from modules that their name contains `prelude` are ignored.
+    ///
+    /// ### Why is this bad?
+    /// The names imported by name are brought in by the wildcard as well, so mixing both styles
+    /// makes it harder to tell which of the imports a name comes from.
+    ///
+    /// ### Known problems
+    /// Imports are grouped by the path they are written with, so an item imported by name through
+    /// a re-export isn't recognized as coming from the module it is re-exported from.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use serde::*;
+    /// use serde::Serialize;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use serde::{Deserialize, Serialize};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub MIXED_WILDCARD_IMPORTS,
+    pedantic,
+    "modules importing from the same module both with a wildcard and by name"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public wildcard re-exports `pub use _::*` of modules which themselves contain
+    /// public wildcard imports.
+    ///
+    /// ### Why is this bad?
+    /// The items brought in by the inner wildcard are re-exported by the outer one as well. They
+    /// become part of the public API of the crate without being named anywhere, and change whenever
+    /// the module they are imported from changes.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod inner {
+    ///     pub use foo::*;
+    ///     pub struct Own;
+    /// }
+    ///
+    /// pub use inner::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// mod inner {
+    ///     pub use foo::Bar;
+    ///     pub struct Own;
+    /// }
+    ///
+    /// pub use inner::*;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub TRANSITIVE_WILDCARD_IMPORTS,
+    pedantic,
+    "public wildcard re-exports of modules containing public wildcard imports"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in names which are raw identifiers, like
+    /// `r#type`, or contextual keywords, like `union`.
+    ///
+    /// ### Why is this bad?
+    /// Such names are easy to misread as the keyword they are spelled like. With a glob, the
+    /// import doesn't spell them out either, so nothing hints at where they come from.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod items {
+    ///     pub struct r#type;
+    ///     pub fn union() {}
+    /// }
+    ///
+    /// use items::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use items::{r#type, union};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub KEYWORD_LIKE_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing in raw identifiers or contextual keywords"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in a name which a derive macro in the
+    /// same module also generates an item for.
+    ///
+    /// ### Why is this bad?
+    /// The generated item shadows the glob imported one. Neither of them is spelled out in the
+    /// source, so it isn't obvious which one a usage of the name refers to.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod builders {
+    ///     pub struct ConfigBuilder;
+    /// }
+    ///
+    /// use builders::*;
+    ///
+    /// // Generates a `ConfigBuilder` struct.
+    /// #[derive(Builder)]
+    /// struct Config;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use builders::ConfigBuilder as OtherConfigBuilder;
+    ///
+    /// #[derive(Builder)]
+    /// struct Config;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub DERIVE_COLLIDING_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing in names that a derive macro generates items for"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` from a module that defines items behind a
+    /// `#[cfg]` depending on a feature.
+    ///
+    /// ### Why is this bad?
+    /// What the wildcard brings into scope changes with the enabled features. A name that
+    /// resolves to the module's item in one build may resolve to something else, or not at all,
+    /// in another.
+    ///
+    /// ### Known problems
+    /// Items left out by a `#[cfg]` are reported even if they wouldn't be visible from the
+    /// import once enabled, as their visibility isn't known.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod backend {
+    ///     #[cfg(feature = "tokio")]
+    ///     pub fn spawn() {}
+    ///     pub fn block_on() {}
+    /// }
+    ///
+    /// use backend::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[cfg(feature = "tokio")]
+    /// use backend::spawn;
+    /// use backend::block_on;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub CFG_DEPENDENT_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports from modules whose exports depend on enabled features"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use foo::{self, *}` that only bring in a single name besides
+    /// the module itself.
+    /// Where `wildcard_imports` is enabled, it is left to that lint, which suggests the same fix.
+    ///
+    /// ### Why is this bad?
+    /// The module is already imported by name, so importing the one item that is used by name as
+    /// well is just as short, and tells the reader where that item comes from.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use std::fmt::{self, *};
+    ///
+    /// fn fmt(_: &mut fmt::Formatter<'_>) -> Result {
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::fmt::{self, Result};
+    ///
+    /// fn fmt(_: &mut fmt::Formatter<'_>) -> Result {
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SELF_AND_WILDCARD_IMPORTS,
+    pedantic,
+    "`use foo::{self, *}` imports which only bring in a single name with the wildcard"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring traits of another crate into scope.
+    ///
+    /// ### Why is this bad?
+    /// The methods of a trait in scope can be called without naming the trait anywhere. If a
+    /// later minor version of the dependency adds a method to one of these traits, calls to a
+    /// method of the same name may silently resolve to it instead.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use std::io::*;
+    ///
+    /// fn read(mut input: impl Read) -> Result<String> {
+    ///     let mut s = String::new();
+    ///     input.read_to_string(&mut s)?;
+    ///     Ok(s)
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::io::{Read, Result};
+    ///
+    /// fn read(mut input: impl Read) -> Result<String> {
+    ///     let mut s = String::new();
+    ///     input.read_to_string(&mut s)?;
+    ///     Ok(s)
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub TRAIT_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing traits of another crate into scope"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the same wildcard import `use _::*` being repeated in several modules of a crate.
+    ///
+    /// ### Why is this bad?
+    /// Every module needs its own copy of the import, and they have to be kept in sync by hand.
+    /// A single `pub(crate) use foo::*` in a shared module lets the others import from there.
+    ///
+    /// Whether this is an improvement is debatable, as it hides where the names come from behind
+    /// one more layer, so this lint is informational.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod a {
+    ///     use crate::shapes::*;
+    /// }
+    /// mod b {
+    ///     use crate::shapes::*;
+    /// }
+    /// mod c {
+    ///     use crate::shapes::*;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// mod shared {
+    ///     pub(crate) use crate::shapes::*;
+    /// }
+    /// mod a {
+    ///     use crate::shared::*;
+    /// }
+    /// mod b {
+    ///     use crate::shared::*;
+    /// }
+    /// mod c {
+    ///     use crate::shared::*;
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub REPEATED_WILDCARD_IMPORTS,
+    nursery,
+    "the same wildcard import repeated in several modules"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that are only used to call methods of the traits they
+    /// bring into scope.
+    ///
+    /// ### Why is this bad?
+    /// Nothing in the module names these extension traits, so a reader has no way to tell why the
+    /// glob is there or where the methods come from. An explicit import of the traits, with a
+    /// comment on the methods they provide, documents both.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use itertools::*;
+    ///
+    /// fn sorted(v: Vec<u32>) -> Vec<u32> {
+    ///     v.into_iter().sorted().collect()
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// // For `sorted`.
+    /// use itertools::Itertools;
+    ///
+    /// fn sorted(v: Vec<u32>) -> Vec<u32> {
+    ///     v.into_iter().sorted().collect()
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub EXTENSION_TRAIT_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports only used for the methods of extension traits"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Reports all wildcard imports `use _::*` of the crate at once, together with the names that are
+    /// used through each of them. Besides being enabled itself, this lint needs `wildcard-imports-report`
+    /// to be set in `clippy.toml`.
+    ///
+    /// ### Why is this bad?
+    /// It isn't. The report is meant for tooling that expands many wildcard imports in one go, for
+    /// example ahead of a large refactor. Each note of the report has the form
+    /// `<file>:<line>:<column>: <path>::{<names>}`, where the location is the start of the `use`
+    /// item and `<names>` is empty for an unused glob.
+    ///
+    /// ### Example
+    /// ```toml
+    /// wildcard-imports-report = true
+    /// ```
+    ///
+    /// ```rust,ignore
+    /// use crate::shapes::*;
+    ///
+    /// fn area(s: &Square) -> f64 {
+    ///     s.0 * s.0
+    /// }
+    /// ```
+    /// is reported as `src/lib.rs:1:1: crate::shapes::{Square}`.
+    #[clippy::version = "1.81.0"]
+    pub WILDCARD_IMPORTS_REPORT,
+    restriction,
+    "a report of all wildcard imports of the crate and the names used through them"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for unused wildcard imports `use _::*` that have an `#[allow(unused_imports)]` or
+    /// `#[allow(unused)]` attribute of their own.
+    ///
+    /// ### Why is this bad?
+    /// The attribute silences `unused_imports`, so a glob that no longer brings anything into scope
+    /// stays around as dead code. It was likely added for names that have since been removed or are
+    /// only used in some configurations.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[allow(unused_imports)]
+    /// use crate::shapes::*;
+    /// ```
+    ///
+    /// Use instead: remove the import.
+    #[clippy::version = "1.81.0"]
+    pub MASKED_UNUSED_WILDCARD_IMPORTS,
+    pedantic,
+    "unused wildcard imports whose `unused_imports` warning is allowed"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring `#[unstable]` items into scope whose feature
+    /// is enabled in the current crate.
+    ///
+    /// ### Why is this bad?
+    /// Once a feature is enabled, its unstable items can be used through the glob without ever being
+    /// named in an import, which makes it easy to miss that the code depends on a nightly compiler.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #![feature(iter_intersperse)]
+    ///
+    /// use std::iter::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// #![feature(iter_intersperse)]
+    ///
+    /// use std::iter::{Intersperse, Peekable};
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub UNSTABLE_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing unstable items into scope"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard re-exports `pub use _::*` that re-export functions, constants, statics or type
+    /// aliases whose type mentions a type that isn't `pub`.
+    ///
+    /// ### Why is this bad?
+    /// Such items are part of the public API, but callers can't name the types they take or return.
+    /// The glob re-exports them without any sign at the `pub use` that it is widening the API this way.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod shapes {
+    ///     pub(crate) struct Cache;
+    ///     pub fn new_cache() -> Cache { Cache }
+    ///     pub fn area() -> f64 { 0.0 }
+    /// }
+    ///
+    /// pub use shapes::*;
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub use shapes::area;
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub PRIVATE_INTERFACE_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard re-exports of items whose signature contains private types"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules of the crate that all import the same set of modules with private wildcard
+    /// imports `use _::*`, suggesting to collect them in a `prelude` module instead.
+    ///
+    /// ### Why is this bad?
+    /// Each module has to list the same globs and keep them in sync by hand. A crate-local prelude
+    /// states once which names the crate uses everywhere, and a single glob imports all of them.
+    ///
+    /// Only modules importing exactly the same modules are grouped. A single repeated glob is covered
+    /// by `repeated_wildcard_imports` instead.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod a {
+    ///     use crate::shapes::*;
+    ///     use crate::colors::*;
+    /// }
+    /// mod b {
+    ///     use crate::shapes::*;
+    ///     use crate::colors::*;
+    /// }
+    /// mod c {
+    ///     use crate::shapes::*;
+    ///     use crate::colors::*;
+    /// }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// mod prelude {
+    ///     pub(crate) use crate::colors::*;
+    ///     pub(crate) use crate::shapes::*;
+    /// }
+    /// mod a {
+    ///     use crate::prelude::*;
+    /// }
+    /// mod b {
+    ///     use crate::prelude::*;
+    /// }
+    /// mod c {
+    ///     use crate::prelude::*;
+    /// }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub PRELUDE_CANDIDATE_WILDCARD_IMPORTS,
+    nursery,
+    "the same set of wildcard imports repeated across modules, which could be a prelude"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` whose used names are all re-exported by one of the
+    /// modules the glob's module is nested in, so that the glob could go through a shorter path.
+    ///
+    /// ### Why is this bad?
+    /// Items are usually meant to be used through the module that re-exports them, like `HashMap`
+    /// through `std::collections` rather than `std::collections::hash_map`. Going through the longer
+    /// path ties the import to where the items happen to be defined.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// use std::collections::hash_map::*;
+    ///
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```no_run
+    /// use std::collections::*;
+    ///
+    /// let map: HashMap<u32, u32> = HashMap::new();
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub SHORTER_PATH_WILDCARD_IMPORTS,
+    pedantic,
+    "wildcard imports from a module whose used items are also exported through a shorter path"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` that bring in functions with the same name as a
+    /// parameter of a function in the same module.
+    ///
+    /// ### Why is this bad?
+    /// Inside the function the parameter shadows the imported function. Since the glob doesn't
+    /// spell out the names it imports, a reader may take a use of the parameter for a call of the
+    /// function, or the other way around.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod settings {
+    ///     pub fn config() -> Config { .. }
+    /// }
+    ///
+    /// use settings::*;
+    ///
+    /// fn apply(config: Config) { .. }
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust,ignore
+    /// use settings::Config;
+    ///
+    /// fn apply(config: Config) { .. }
+    /// ```
+    #[clippy::version = "1.81.0"]
+    pub PARAM_SHADOWED_WILDCARD_IMPORTS,
+    nursery,
+    "wildcard imports bringing in functions that are shadowed by function parameters"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for wildcard imports `use _::*` of modules that have no items visible to the importing
+    /// module, like a module with only private items.
+    ///
+    /// ### Why is this bad?
+    /// The import brings nothing into scope. It is likely left over from items that have since been
+    /// removed or made private, and only makes the reader look for names that aren't there.
+    ///
+    /// ### Known problems
+    /// Modules with items that are `#[cfg]`-ed out are not linted, as the import can bring them in
+    /// on other targets or with other features.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod internal {
+    ///     fn helper() {}
+    /// }
+    ///
+    /// use internal::*;
+    /// ```
+    ///
+    /// Use instead: remove the import.
+    #[clippy::version = "1.81.0"]
+    pub EMPTY_WILDCARD_IMPORT,
+    suspicious,
+    "wildcard imports of modules without any items visible to the importing module"
+}
+
+pub struct WildcardImports {
+    warn_on_all: bool,
+    test_modules_deep: u32,
+    allowed_segments: FxHashSet<String>,
+    allow_same_workspace: bool,
+    workspace_root: Option<PathBuf>,
+    min_file_lines: u64,
+    pub_wildcard_max_reexports: u64,
+    mixed_style: MixedWildcardImportsStyle,
+    qualified_usages: qualified_wildcard_imports::QualifiedUsages,
+    function_usages: single_function_wildcard_imports::FunctionUsages,
+    repeated_globs: repeated_wildcard_imports::RepeatedGlobs,
+    glob_sets: prelude_candidate_wildcard_imports::GlobSets,
+    report_globs: bool,
+    generated_file_patterns: Vec<String>,
+    marker_comment: String,
+    only_public_modules: bool,
+    manifest_dir: Option<PathBuf>,
+    /// The source ranges that were already linted, so that a glob whose item is expanded several
+    /// times from the same macro definition is only reported once.
+    linted_ranges: FxHashSet<(BytePos, BytePos)>,
+    glob_report: wildcard_imports_report::GlobReport,
+}
+
+impl WildcardImports {
+    #[expect(clippy::too_many_arguments)]
+    pub fn new(
+        warn_on_all: bool,
+        allowed_wildcard_imports: FxHashSet<String>,
+        allow_same_workspace_glob: bool,
+        min_file_lines: u64,
+        pub_wildcard_max_reexports: u64,
+        mixed_style: MixedWildcardImportsStyle,
+        report_globs: bool,
+        generated_file_patterns: Vec<String>,
+        marker_comment: String,
+        only_lint_public_modules: bool,
+    ) -> Self {
+        Self {
+            warn_on_all,
+            test_modules_deep: 0,
+            allowed_segments: allowed_wildcard_imports,
+            allow_same_workspace: allow_same_workspace_glob,
+            workspace_root: None,
+            min_file_lines,
+            pub_wildcard_max_reexports,
+            mixed_style,
+            qualified_usages: qualified_wildcard_imports::QualifiedUsages::default(),
+            function_usages: single_function_wildcard_imports::FunctionUsages::default(),
+            repeated_globs: repeated_wildcard_imports::RepeatedGlobs::default(),
+            glob_sets: prelude_candidate_wildcard_imports::GlobSets::default(),
+            report_globs,
+            generated_file_patterns,
+            marker_comment,
+            only_public_modules: only_lint_public_modules,
+            manifest_dir: None,
+            linted_ranges: FxHashSet::default(),
+            glob_report: wildcard_imports_report::GlobReport::default(),
+        }
+    }
+}
+
+impl_lint_pass!(WildcardImports => [
+    ENUM_GLOB_USE,
+    WILDCARD_IMPORTS,
+    QUALIFIED_WILDCARD_IMPORTS,
+    TYPE_AND_VALUE_WILDCARD_IMPORTS,
+    SINGLE_FUNCTION_WILDCARD_IMPORTS,
+    BUILTIN_MACRO_WILDCARD_IMPORTS,
+    CASE_COLLIDING_WILDCARD_IMPORTS,
+    DUPLICATE_PATH_WILDCARD_IMPORTS,
+    CRATE_API_WILDCARD_IMPORTS,
+    LARGE_PUB_WILDCARD_IMPORTS,
+    MIXED_WILDCARD_IMPORTS,
+    TRANSITIVE_WILDCARD_IMPORTS,
+    KEYWORD_LIKE_WILDCARD_IMPORTS,
+    DERIVE_COLLIDING_WILDCARD_IMPORTS,
+    CFG_DEPENDENT_WILDCARD_IMPORTS,
+    SELF_AND_WILDCARD_IMPORTS,
+    TRAIT_WILDCARD_IMPORTS,
+    REPEATED_WILDCARD_IMPORTS,
+    EXTENSION_TRAIT_WILDCARD_IMPORTS,
+    WILDCARD_IMPORTS_REPORT,
+    MASKED_UNUSED_WILDCARD_IMPORTS,
+    UNSTABLE_WILDCARD_IMPORTS,
+    PRIVATE_INTERFACE_WILDCARD_IMPORTS,
+    PRELUDE_CANDIDATE_WILDCARD_IMPORTS,
+    SHORTER_PATH_WILDCARD_IMPORTS,
+    PARAM_SHADOWED_WILDCARD_IMPORTS,
+    EMPTY_WILDCARD_IMPORT,
+]);
+
+impl LateLintPass<'_> for WildcardImports {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+        if self.allow_same_workspace {
+            self.workspace_root = workspace_root(cx);
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if cx.sess().is_test_crate() {
+            return;
+        }
+
+        if is_test_module_or_function(cx.tcx, item) {
+            self.test_modules_deep = self.test_modules_deep.saturating_add(1);
+        }
+        if let ItemKind::Use(use_path, UseKind::Glob) = &item.kind
+            && !item.span.from_expansion()
+            && !self.is_allowed_glob(cx, item, use_path)
+        {
+            self.qualified_usages.check_glob(item, use_path);
+            type_and_value_wildcard_imports::check(cx, item, use_path);
+            self.function_usages.check_glob(cx, item, use_path);
+            builtin_macro_wildcard_imports::check(cx, item, use_path);
+            case_colliding_wildcard_imports::check(cx, item, use_path);
+            duplicate_path_wildcard_imports::check(cx, item, use_path);
+            crate_api_wildcard_imports::check(cx, item, use_path);
+            large_pub_wildcard_imports::check(cx, item, use_path, self.pub_wildcard_max_reexports);
+            transitive_wildcard_imports::check(cx, item, use_path);
+            keyword_like_wildcard_imports::check(cx, item, use_path);
+            derive_colliding_wildcard_imports::check(cx, item, use_path);
+            cfg_dependent_wildcard_imports::check(cx, item, use_path);
+            self_and_wildcard_imports::check(cx, item, use_path);
+            trait_wildcard_imports::check(cx, item, use_path);
+            self.repeated_globs.check_glob(cx, item, use_path);
+            self.glob_sets.check_glob(cx, item, use_path);
+            extension_trait_wildcard_imports::check(cx, item, use_path);
+            masked_unused_wildcard_imports::check(cx, item, use_path);
+            unstable_wildcard_imports::check(cx, item, use_path);
+            private_interface_wildcard_imports::check(cx, item, use_path);
+            shorter_path_wildcard_imports::check(cx, item, use_path);
+            param_shadowed_wildcard_imports::check(cx, item, use_path);
+            empty_wildcard_import::check(cx, item, use_path);
+            if self.report_globs {
+                self.glob_report.check_glob(cx, item, use_path);
+            }
+        }
+        // Test frameworks like `test-case` expand each test into a module with a `use super::*`, which
+        // isn't worth linting even with `warn-on-all-wildcard-imports`.
+        if item.span.from_expansion() && is_test_attribute_macro(item.span) {
+            return;
+        }
+        let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+        if cx.tcx.visibility(item.owner_id.def_id) != ty::Visibility::Restricted(module.to_def_id()) {
+            return;
+        }
+        if let ItemKind::Use(use_path, UseKind::Glob) = &item.kind
+            && (self.warn_on_all || !self.check_exceptions(cx, item, use_path))
+            && let used_imports = without_explicit_imports(
+                cx,
+                item,
+                use_path.res[0],
+                cx.tcx.names_imported_by_glob_use(item.owner_id.def_id),
+            )
+            // An unused glob is already handled by `unused_imports`, which suggests removing it. Lints
+            // only see the current state of the crate, so there is no telling whether the last usage
+            // was removed by a recent edit.
+            && !used_imports.is_empty()
+            && !used_imports.contains(&kw::Underscore)
+        {
+            let mut applicability = Applicability::MachineApplicable;
+            let import_source_snippet = snippet_with_applicability(cx, use_path.span, "..", &mut applicability);
+            // Naming an item whose definition isn't accessible here would make the explicit import fail to
+            // compile, even though the glob could bring it in.
+            if !are_accessible(cx, item, use_path.res[0], &used_imports) {
+                applicability = Applicability::MaybeIncorrect;
+            }
+            // `#[macro_export]` macros live at the root of their crate, wherever they are defined. Naming one
+            // through the glob's module only works as long as that module keeps re-exporting it.
+            if imports_exported_macro(cx, use_path.res[0], &used_imports) {
+                applicability = Applicability::MaybeIncorrect;
+            }
+            // A name the glob's module re-exports from several modules, one per namespace, stands for all
+            // of them once imported explicitly, which might not be what the user expects.
+            let several_origins = reexported_from_several_modules(cx, use_path.res[0], &used_imports);
+            if !several_origins.is_empty() {
+                applicability = Applicability::MaybeIncorrect;
+            }
+            let (span, braced_glob) = if import_source_snippet.is_empty() {
+                // This is a `_::{_, *}` import
+                // In this case `use_path.span` is empty and ends directly in front of the `*`,
+                // so we need to extend it by one byte. Only the `*` is replaced, so the other
+                // entries of the group keep their order and the fix doesn't churn them.
+                (use_path.span.with_hi(use_path.span.hi() + BytePos(1)), true)
+            } else {
+                // In this case, the `use_path.span` ends right before the `::*`, so we need to
+                // extend it up to the `*`. Since it is hard to find the `*` in weird
+                // formatting like `use _ ::  *;`, we extend it up to, but not including the
+                // `;`. In nested imports, like `use _::{inner::*, _}` there is no `;` and we
+                // can just use the end of the item span
+                let mut span = use_path.span.with_hi(item.span.hi());
+                if snippet(cx, span, "").ends_with(';') {
+                    span = use_path.span.with_hi(item.span.hi() - BytePos(1));
+                }
+                (span, false)
+            };
+            if !self.linted_ranges.insert((span.lo(), span.hi())) {
+                return;
+            }
+
+            // A name that is renamed by a sibling import of the same braced group, like `Bar` in
+            // `use foo::{Bar as MyBar, *}`, is only part of `used_imports` if it is also used under
+            // its original name. Keeping it next to the rename is therefore correct.
+            let mut imports = used_imports.items().map(ToString::to_string).into_sorted_stable_ord();
+            let imports_string = if imports.len() == 1 {
+                imports.pop().unwrap()
+            } else if braced_glob {
+                imports.join(", ")
+            } else {
+                format!("{{{}}}", imports.join(", "))
+            };
+
+            // In the 2015 edition, `use` paths are resolved from the crate root, so the explicit imports of
+            // `use krate::*` still go through its `extern crate krate;` item. The item is therefore left
+            // alone, even if the glob was its only use.
+            let sugg = if braced_glob {
+                imports_string
+            } else {
+                format!("{import_source_snippet}::{imports_string}")
+            };
+
+            // Glob imports always have a single resolution.
+            let (lint, message) = if let Res::Def(DefKind::Enum, _) = use_path.res[0] {
+                (ENUM_GLOB_USE, "usage of wildcard import for enum variants")
+            } else {
+                (WILDCARD_IMPORTS, "usage of wildcard import")
+            };
+
+            // Macros that are re-exported with `pub use` are easily lost when rewriting the import by hand.
+            let macros = reexported_macros(cx, use_path.res[0], &used_imports);
+            let line_widths = line_widths(cx, span, &sugg).filter(|&(width, fixed_width)| fixed_width > width);
+            span_lint_and_then(cx, lint, span, message, |diag| {
+                diag.span_suggestion(span, "try", sugg, applicability);
+                if let Some((width, fixed_width)) = line_widths {
+                    diag.span_note(
+                        span,
+                        format!(
+                            "with the suggested import, this line would be {fixed_width} characters long instead of {width}"
+                        ),
+                    );
+                }
+                if !macros.is_empty() {
+                    diag.note(format!(
+                        "this import also brings in the re-exported macro{}: {}",
+                        if macros.len() == 1 { "" } else { "s" },
+                        macros.join(", ")
+                    ));
+                }
+                for (name, origins) in several_origins {
+                    let mut paths = origins
+                        .into_iter()
+                        .map(|origin| format!("`{}`", cx.tcx.def_path_str(origin)))
+                        .collect::<Vec<_>>();
+                    paths.sort_unstable();
+                    diag.note(format!(
+                        "`{name}` is re-exported from several modules: {}",
+                        paths.join(", ")
+                    ));
+                }
+            });
+        }
+    }
+
+    fn check_mod(&mut self, cx: &LateContext<'_>, module: &Mod<'_>, _: HirId) {
+        if !cx.sess().is_test_crate() {
+            mixed_wildcard_imports::check_mod(cx, module, self.mixed_style);
+        }
+    }
+
+    fn check_item_post(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if is_test_module_or_function(cx.tcx, item) {
+            self.test_modules_deep = self.test_modules_deep.saturating_sub(1);
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, hir_id: HirId) {
+        self.qualified_usages.check_path(cx, path, hir_id);
+        self.function_usages.check_path(cx, path, hir_id);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'_>) {
+        if !cx.sess().is_test_crate() {
+            self.qualified_usages.check_crate_post(cx);
+            self.function_usages.check_crate_post(cx);
+            self.repeated_globs.check_crate_post(cx);
+            self.glob_sets.check_crate_post(cx);
+            self.glob_report.check_crate_post(cx);
+        }
+    }
+}
+
+impl WildcardImports {
+    /// The exceptions that apply to the other wildcard import lints as well: preludes, `use super::*`
+    /// in test modules and the imports of `allowed-wildcard-imports`.
+    fn is_allowed_glob(&self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) -> bool {
+        let segments = use_path.segments;
+        (is_prelude_import(segments) && !is_implicit_prelude_import(cx, item, use_path))
+            || (is_super_only_import(segments) && self.test_modules_deep > 0)
+            || is_allowed_via_config(segments, &self.allowed_segments)
+    }
+
+    fn check_exceptions(&self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) -> bool {
+        item.span.from_expansion()
+            || self.is_allowed_glob(cx, item, use_path)
+            || (self.allow_same_workspace && is_same_workspace_import(cx, use_path, self.workspace_root.as_deref()))
+            || is_in_short_file(cx, item, self.min_file_lines)
+            || is_in_generated_file(cx, item, &self.generated_file_patterns)
+            || has_marker_comment(cx, item, &self.marker_comment)
+            || (self.only_public_modules && !is_in_public_module(cx, item))
+            || is_in_doc_test(cx, item)
+            || is_dependency_import_in_non_harness_test_file(cx, item, use_path, self.manifest_dir.as_deref())
+            || is_in_lazy_initializer(cx, item)
+            || is_in_anonymous_const(cx, item)
+    }
+}
+
+/// Returns the children of the module `def_id`, which can come from the local or an external crate.
+fn module_children<'tcx>(cx: &LateContext<'tcx>, def_id: DefId) -> &'tcx [ModChild] {
+    match def_id.as_local() {
+        Some(local_id) => cx.tcx.module_children_local(local_id),
+        None => cx.tcx.module_children(def_id),
+    }
+}
+
+/// Removes the names from `used_imports` that a sibling import of the same module already imports by
+/// name from the glob's module or enum, so that the suggestion doesn't import them a second time.
+fn without_explicit_imports(
+    cx: &LateContext<'_>,
+    item: &Item<'_>,
+    res: Res,
+    used_imports: &UnordSet<Symbol>,
+) -> UnordSet<Symbol> {
+    let Some(def_id) = res.opt_def_id() else {
+        return used_imports.items().copied().collect();
+    };
+    let children = module_children(cx, def_id);
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let explicit = cx
+        .tcx
+        .hir()
+        .module_items(module)
+        .map(|id| cx.tcx.hir().item(id))
+        .filter_map(|sibling| match sibling.kind {
+            ItemKind::Use(path, UseKind::Single) if !sibling.span.from_expansion() => Some((sibling, path)),
+            _ => None,
+        })
+        // A renamed import doesn't bring the original name into scope.
+        .filter(|(sibling, path)| {
+            path.segments
+                .last()
+                .is_some_and(|seg| seg.ident.name == sibling.ident.name)
+        })
+        .filter(|(sibling, path)| {
+            children.iter().any(|child| {
+                child.ident.name == sibling.ident.name
+                    && path.res.iter().any(|res| res.opt_def_id() == child.res.opt_def_id())
+            })
+        })
+        .map(|(sibling, _)| sibling.ident.name)
+        .collect::<FxHashSet<_>>();
+    used_imports
+        .items()
+        .copied()
+        .filter(|name| !explicit.contains(name))
+        .collect()
+}
+
+/// Checks that every name in `names` refers to at least one child of the glob's module or enum whose
+/// definition is accessible from the module containing `item`.
+fn are_accessible(cx: &LateContext<'_>, item: &Item<'_>, res: Res, names: &UnordSet<Symbol>) -> bool {
+    let Some(def_id) = res.opt_def_id() else {
+        return true;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id).to_def_id();
+    let children = module_children(cx, def_id);
+    names.items().all(|name| {
+        children.iter().filter(|child| child.ident.name == *name).any(|child| {
+            child.res.opt_def_id().map_or(true, |def_id| {
+                cx.tcx.visibility(def_id).is_accessible_from(module, cx.tcx)
+            })
+        })
+    })
+}
+
+/// Checks whether any name in `names` refers to a `#[macro_export]` macro that the glob's module
+/// only re-exports, because it isn't the root of the macro's crate.
+fn imports_exported_macro(cx: &LateContext<'_>, res: Res, names: &UnordSet<Symbol>) -> bool {
+    let Res::Def(DefKind::Mod, def_id) = res else {
+        return false;
+    };
+    module_children(cx, def_id).iter().any(|child| {
+        matches!(child.res, Res::Def(DefKind::Macro(MacroKind::Bang), macro_id)
+            if names.contains(&child.ident.name)
+                && cx.tcx.has_attr(macro_id, sym::macro_export)
+                && macro_id.krate.as_def_id() != def_id)
+    })
+}
+
+fn reexported_macros(cx: &LateContext<'_>, res: Res, used_imports: &UnordSet<Symbol>) -> Vec<String> {
+    let Res::Def(DefKind::Mod, def_id) = res else {
+        return Vec::new();
+    };
+    let mut macros = module_children(cx, def_id)
+        .iter()
+        .filter(|child| {
+            matches!(child.res, Res::Def(DefKind::Macro(_), _))
+                && !child.reexport_chain.is_empty()
+                && used_imports.contains(&child.ident.name)
+        })
+        .map(|child| format!("`{}`", child.ident))
+        .collect::<Vec<_>>();
+    macros.sort_unstable();
+    macros
+}
+
+/// Returns the names in `used_imports` that the glob's module re-exports from more than one module,
+/// together with the items they refer to, sorted by name.
+fn reexported_from_several_modules(
+    cx: &LateContext<'_>,
+    res: Res,
+    used_imports: &UnordSet<Symbol>,
+) -> Vec<(Symbol, Vec<DefId>)> {
+    let Res::Def(DefKind::Mod, def_id) = res else {
+        return Vec::new();
+    };
+    let mut origins = module_children(cx, def_id)
+        .iter()
+        .filter(|child| !child.reexport_chain.is_empty() && used_imports.contains(&child.ident.name))
+        .filter_map(|child| {
+            let origin = child.res.opt_def_id()?;
+            // A unit or tuple struct is re-exported once per namespace, as the type and its constructor.
+            let origin = if let DefKind::Ctor(..) = cx.tcx.def_kind(origin) {
+                cx.tcx.parent(origin)
+            } else {
+                origin
+            };
+            Some((child.ident.name, origin))
+        })
+        .collect::<Vec<_>>();
+    origins.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+    origins
+        .chunk_by(|(a, _), (b, _)| a == b)
+        .map(|group| {
+            (
+                group[0].0,
+                group.iter().map(|&(_, origin)| origin).unique().collect::<Vec<_>>(),
+            )
+        })
+        .filter(|(_, origins)| {
+            origins
+                .iter()
+                .any(|&origin| cx.tcx.opt_parent(origin) != cx.tcx.opt_parent(origins[0]))
+        })
+        .collect()
+}
+
+/// Returns the width of the line containing `span`, before and after `span` is replaced by `sugg`.
+fn line_widths(cx: &LateContext<'_>, span: Span, sugg: &str) -> Option<(usize, usize)> {
+    let source_map = cx.sess().source_map();
+    let before = source_map.span_to_prev_source(span).ok()?;
+    let snippet = source_map
+        .span_to_snippet(span)
+        .ok()
+        .filter(|snippet| !snippet.contains('\n'))?;
+    let after = source_map.span_to_next_source(span).ok()?;
+    let before = before.rsplit('\n').next().unwrap_or_default();
+    let after = after.split('\n').next().unwrap_or_default().trim_end_matches('\r');
+    let rest = before.chars().count() + after.chars().count();
+    Some((rest + snippet.chars().count(), rest + sugg.chars().count()))
+}
+
+/// Checks whether `span` comes from the expansion of an attribute macro whose name contains `test`,
+/// like `#[test_case(..)]`.
+fn is_test_attribute_macro(span: Span) -> bool {
+    span.macro_backtrace()
+        .any(|expn| matches!(expn.kind, ExpnKind::Macro(MacroKind::Attr, name) if name.as_str().contains("test")))
+}
+
+/// Checks whether `item` is nested in the initializer of a `lazy_static!` or of a static whose
+/// type is `once_cell`'s `Lazy`.
+fn is_in_lazy_initializer(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    cx.tcx.hir().parent_iter(item.hir_id()).any(|(_, node)| {
+        let Node::Item(parent) = node else {
+            return false;
+        };
+        let is_once_cell_lazy = matches!(parent.kind, ItemKind::Static(..) | ItemKind::Const(..))
+            && matches!(
+                cx.tcx.type_of(parent.owner_id).instantiate_identity().kind(),
+                ty::Adt(adt, _) if match_def_path(cx, adt.did(), &paths::ONCE_CELL_SYNC_LAZY)
+                    || match_def_path(cx, adt.did(), &paths::ONCE_CELL_UNSYNC_LAZY)
+            );
+        is_once_cell_lazy
+            || parent.span.macro_backtrace().any(|expn| {
+                matches!(
+                    expn.kind,
+                    ExpnKind::Macro(MacroKind::Bang, name)
+                        if matches!(name.as_str(), "lazy_static" | "__lazy_static_internal")
+                )
+            })
+    })
+}
+
+/// Checks whether `item` is nested in an anonymous `const _: () = { ... };`, which derive macros and
+/// other code generators use to scope their helper items and imports.
+fn is_in_anonymous_const(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    cx.tcx.hir().parent_iter(item.hir_id()).any(|(_, node)| {
+        matches!(
+            node,
+            Node::Item(parent) if matches!(parent.kind, ItemKind::Const(..)) && parent.ident.name == kw::Underscore
+        )
+    })
+}
+
+// Allow "...prelude::..::*" imports.
+// Many crates have a prelude, and it is imported as a glob by design.
+fn is_prelude_import(segments: &[PathSegment<'_>]) -> bool {
+    segments
+        .iter()
+        .any(|ps| ps.ident.as_str().contains(sym::prelude.as_str()))
+}
+
+// The prelude of the standard library is only worth importing by hand in modules that don't get
+// it implicitly, because they or one of their parents are `#[no_implicit_prelude]`.
+fn is_implicit_prelude_import(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) -> bool {
+    // Glob imports always have a single resolution.
+    let Some(def_id) = use_path.res[0].opt_def_id() else {
+        return false;
+    };
+    if !matches!(cx.tcx.crate_name(def_id.krate), sym::std | sym::core)
+        || cx.tcx.def_path(def_id).data.first().map(|it| it.data) != Some(DefPathData::TypeNs(sym::prelude))
+    {
+        return false;
+    }
+    let mut module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id).to_local_def_id();
+    loop {
+        if cx.tcx.has_attr(module, sym::no_implicit_prelude) {
+            return false;
+        }
+        match cx.tcx.opt_local_parent(module) {
+            Some(parent) => module = parent,
+            None => return true,
+        }
+    }
+}
+
+// Allow "super::*" imports in tests.
+// There is no need to special case the crate root here: rustc already rejects `super` in paths
+// there, so such an import never reaches this lint.
+fn is_super_only_import(segments: &[PathSegment<'_>]) -> bool {
+    segments.len() == 1 && segments[0].ident.name == kw::Super
+}
+
+// Allow skipping imports containing user configured segments,
+// i.e. "...::utils::...::*" if user put `allowed-wildcard-imports = ["utils"]` in `Clippy.toml`
+fn is_allowed_via_config(segments: &[PathSegment<'_>], allowed_segments: &FxHashSet<String>) -> bool {
+    // segment matching need to be exact instead of using 'contains', in case user unintentionally put
+    // a single character in the config thus skipping most of the warnings.
+    segments.iter().any(|seg| allowed_segments.contains(seg.ident.as_str()))
+}
+
+// Allow imports from the current crate and the other crates of its workspace if the user put
+// `allow-same-workspace-glob = true` in `clippy.toml`.
+fn is_same_workspace_import(
+    cx: &LateContext<'_>,
+    use_path: &UsePath<'_>,
+    workspace_root: Option<&std::path::Path>,
+) -> bool {
+    use_path
+        .res
+        .iter()
+        .find_map(Res::opt_def_id)
+        .is_some_and(|def_id| is_same_workspace_crate(cx, def_id.krate, workspace_root))
+}
+
+// Cargo runs the compiler from the root of the workspace, and sets `CARGO_PRIMARY_PACKAGE` for the
+// workspace members it was asked to check. Without it, there is no workspace besides the current crate.
+fn workspace_root(cx: &LateContext<'_>) -> Option<PathBuf> {
+    env::var_os("CARGO_PRIMARY_PACKAGE")?;
+    cx.sess().opts.working_dir.local_path().map(ToOwned::to_owned)
+}
+
+// The members of a workspace, and the path dependencies between them, are located below its root.
+// Registry and git dependencies are unpacked below `$CARGO_HOME` instead, and the standard library
+// comes from the sysroot.
+fn is_same_workspace_crate(cx: &LateContext<'_>, krate: CrateNum, workspace_root: Option<&std::path::Path>) -> bool {
+    if krate == LOCAL_CRATE {
+        return true;
+    }
+    let Some(workspace_root) = workspace_root else {
+        return false;
+    };
+    let span = cx.tcx.def_span(krate.as_def_id());
+    match cx.sess().source_map().span_to_filename(span) {
+        FileName::Real(RealFileName::LocalPath(path)) => path.starts_with(workspace_root),
+        _ => false,
+    }
+}
+
+// Allow all imports in files whose name contains one of the `wildcard-imports-generated-file-patterns`
+// in `Clippy.toml` as a word. Generated code, like FFI bindings pulled in with `#[path = "..."]`, uses
+// globs on purpose.
+fn is_in_generated_file(cx: &LateContext<'_>, item: &Item<'_>, patterns: &[String]) -> bool {
+    let FileName::Real(RealFileName::LocalPath(path)) = cx.sess().source_map().span_to_filename(item.span) else {
+        return false;
+    };
+    path.file_name().and_then(|name| name.to_str()).is_some_and(|name| {
+        name.split(['_', '-', '.'])
+            .any(|word| patterns.iter().any(|pattern| word.eq_ignore_ascii_case(pattern)))
+    })
+}
+
+// Allow imports directly below a line comment consisting of the `wildcard-imports-marker-comment` in
+// `Clippy.toml`, e.g. `// clippy:wildcard-ok`. Attributes between the comment and the import are skipped.
+fn has_marker_comment(cx: &LateContext<'_>, item: &Item<'_>, marker: &str) -> bool {
+    if marker.is_empty() {
+        return false;
+    }
+    let Ok(use_line) = cx.sess().source_map().lookup_line(item.span.lo()) else {
+        return false;
+    };
+    (0..use_line.line)
+        .rev()
+        .map_while(|line| use_line.sf.get_line(line))
+        .map(|line| line.trim().to_owned())
+        .find(|line| !line.starts_with("#["))
+        .is_some_and(|line| line.strip_prefix("//").is_some_and(|comment| comment.trim() == marker))
+}
+
+// Allow imports in modules that can't be reached from outside the crate if the user put
+// `wildcard-imports-only-public-modules = true` in `Clippy.toml`.
+fn is_in_public_module(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    cx.effective_visibilities.is_exported(module.to_local_def_id())
+}
+
+// Allow imports from other crates, except those of the sysroot, in the files of the `tests`
+// directory of a package, like `use test_utils::*` in a helper module of a `harness = false` test.
+// Test crates built with the harness don't get here. Whether the crate is a dev-dependency isn't
+// checked, since that is only written down in `Cargo.toml`.
+fn is_dependency_import_in_non_harness_test_file(
+    cx: &LateContext<'_>,
+    item: &Item<'_>,
+    use_path: &UsePath<'_>,
+    manifest_dir: Option<&std::path::Path>,
+) -> bool {
+    let Some(manifest_dir) = manifest_dir else {
+        return false;
+    };
+    let is_dependency = use_path.res.iter().filter_map(|res| res.opt_def_id()).any(|def_id| {
+        !def_id.is_local()
+            && !cx
+                .tcx
+                .used_crate_source(def_id.krate)
+                .paths()
+                .any(|path| path.starts_with(&cx.sess().sysroot))
+    });
+    if !is_dependency {
+        return false;
+    }
+    // Relative paths are relative to the directory the compiler runs in, which is the root of the
+    // workspace under Cargo.
+    let (FileName::Real(RealFileName::LocalPath(path)), Some(working_dir)) = (
+        cx.sess().source_map().span_to_filename(item.span),
+        cx.sess().opts.working_dir.local_path(),
+    ) else {
+        return false;
+    };
+    working_dir
+        .join(path)
+        .strip_prefix(manifest_dir)
+        .is_ok_and(|path| path.components().next().is_some_and(|dir| dir.as_os_str() == "tests"))
+}
+
+// Allow all imports in doc-tests. Rustdoc wraps the code block in a synthesized `fn main` before
+// compiling it, so the spans don't line up with the doc comment and a fix can't be applied there.
+fn is_in_doc_test(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    matches!(
+        cx.sess().source_map().span_to_filename(item.span),
+        FileName::DocTest(..)
+    )
+}
+
+// Allow all imports in files that are shorter than `wildcard-imports-min-file-lines` in `Clippy.toml`.
+// A glob in a tiny file, like a `lib.rs` with a handful of lines, is not worth linting.
+fn is_in_short_file(cx: &LateContext<'_>, item: &Item<'_>, min_file_lines: u64) -> bool {
+    min_file_lines > 0
+        && (cx.sess().source_map().lookup_source_file(item.span.lo()).count_lines() as u64) < min_file_lines
+}
@@ -0,0 +1,78 @@
+use super::{module_children, PARAM_SHADOWED_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, ItemKind, UseKind, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::{Span, Symbol};
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // A glob inside a function body only applies to that block, not to the whole module.
+    if cx.tcx.def_kind(cx.tcx.local_parent(item.owner_id.def_id)) != DefKind::Mod {
+        return;
+    }
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    // Only functions can be shadowed by a parameter, other values in patterns are matched against
+    // instead.
+    let mut functions = module_children(cx, def_id)
+        .iter()
+        .filter(|child| {
+            matches!(child.res, Res::Def(DefKind::Fn, _)) && child.vis.is_accessible_from(module.to_def_id(), cx.tcx)
+        })
+        .map(|child| child.ident.name)
+        .collect::<FxHashSet<Symbol>>();
+    // Items and single imports of the module itself take precedence over the glob.
+    for id in cx.tcx.hir_module_items(module).free_items() {
+        let local = cx.tcx.hir().item(id);
+        if matches!(local.kind, ItemKind::Fn(..) | ItemKind::Use(_, UseKind::Single)) {
+            functions.remove(&local.ident.name);
+        }
+    }
+    if functions.is_empty() {
+        return;
+    }
+
+    let mut shadowing: Vec<(Span, String)> = Vec::new();
+    // Only the functions of the module itself, the glob isn't in scope in its submodules.
+    for body_owner in cx.tcx.hir_module_items(module).definitions() {
+        if !matches!(cx.tcx.def_kind(body_owner), DefKind::Fn | DefKind::AssocFn) {
+            continue;
+        }
+        // Methods of a trait without a default body have no patterns to bind.
+        let Some(body) = cx.tcx.hir().maybe_body_owned_by(body_owner) else {
+            continue;
+        };
+        for param in body.params {
+            param.pat.each_binding(|_, _, span, ident| {
+                if functions.contains(&ident.name) {
+                    shadowing.push((
+                        span,
+                        format!(
+                            "the parameter `{ident}` of `{}` shadows the imported function",
+                            cx.tcx.item_name(body_owner.to_def_id())
+                        ),
+                    ));
+                }
+            });
+        }
+    }
+    if shadowing.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        PARAM_SHADOWED_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in functions that are shadowed by function parameters",
+        |diag| {
+            for (span, note) in shadowing {
+                diag.span_note(span, note);
+            }
+        },
+    );
+}
@@ -0,0 +1,89 @@
+use super::repeated_wildcard_imports::repeatable_glob;
+use super::PRELUDE_CANDIDATE_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use itertools::Itertools;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::{DefId, LocalModDefId};
+use rustc_hir::{HirId, Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_crate_prefix;
+use rustc_span::Span;
+
+/// The number of modules which need to import the same set of modules with globs for it to be linted.
+const MIN_MODULES: usize = 3;
+
+/// The number of modules a set of globs has to import from. A single module is left to
+/// `repeated_wildcard_imports`.
+const MIN_SOURCES: usize = 2;
+
+struct Glob {
+    hir_id: HirId,
+    span: Span,
+    module: DefId,
+}
+
+/// Collects the private glob imports of the crate by the module containing them.
+#[derive(Default)]
+pub(super) struct GlobSets {
+    globs: FxIndexMap<LocalModDefId, Vec<Glob>>,
+}
+
+impl GlobSets {
+    pub(super) fn check_glob(&mut self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+        let Some((module, scope)) = repeatable_glob(cx, item, use_path) else {
+            return;
+        };
+        self.globs.entry(scope).or_default().push(Glob {
+            hir_id: item.hir_id(),
+            span: item.span,
+            module,
+        });
+    }
+
+    pub(super) fn check_crate_post(&self, cx: &LateContext<'_>) {
+        // Only modules importing exactly the same set of modules are grouped, as a prelude for a
+        // superset would bring unrelated names into the others.
+        let mut sets = FxIndexMap::<Vec<String>, Vec<&[Glob]>>::default();
+        for globs in self.globs.values() {
+            let modules = globs
+                .iter()
+                .map(|glob| with_crate_prefix!(cx.tcx.def_path_str(glob.module)))
+                .sorted()
+                .dedup()
+                .collect::<Vec<_>>();
+            if modules.len() >= MIN_SOURCES {
+                sets.entry(modules).or_default().push(globs);
+            }
+        }
+        for (modules, scopes) in sets {
+            if scopes.len() < MIN_MODULES {
+                continue;
+            }
+            let mut globs = scopes.iter().flat_map(|globs| globs.iter());
+            let Some(first) = globs.next() else {
+                continue;
+            };
+            let reexports = modules
+                .iter()
+                .map(|module| format!("`pub(crate) use {module}::*;`"))
+                .join(", ");
+            span_lint_hir_and_then(
+                cx,
+                PRELUDE_CANDIDATE_WILDCARD_IMPORTS,
+                first.hir_id,
+                first.span,
+                format!(
+                    "the same {} wildcard imports are repeated in {} modules",
+                    modules.len(),
+                    scopes.len()
+                ),
+                |diag| {
+                    diag.span_note(globs.map(|glob| glob.span).collect::<Vec<_>>(), "also imported here");
+                    diag.help(format!(
+                        "consider a `prelude` module with {reexports}, and a single `use crate::prelude::*;` in each module"
+                    ));
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::{module_children, PRIVATE_INTERFACE_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, GenericArgKind, Ty};
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    if !cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+        return;
+    }
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let mut exposing = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_public())
+        .filter_map(|child| {
+            let Res::Def(kind, child_id) = child.res else {
+                return None;
+            };
+            let private = private_types(cx, interface_types(cx, kind, child_id)?);
+            (!private.is_empty()).then(|| format!("`{}` exposes {}", child.ident, private.join(", ")))
+        })
+        .collect::<Vec<_>>();
+    if exposing.is_empty() {
+        return;
+    }
+    exposing.sort_unstable();
+    exposing.dedup();
+
+    span_lint_and_then(
+        cx,
+        PRIVATE_INTERFACE_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard re-export includes items that expose private types",
+        |diag| {
+            for item in exposing {
+                diag.note(item);
+            }
+            diag.help("re-export the items by name, leaving out those with private types in their signature");
+        },
+    );
+}
+
+/// The types that make up the public interface of an item, for the kinds of items that have one.
+fn interface_types<'tcx>(cx: &LateContext<'tcx>, kind: DefKind, def_id: DefId) -> Option<Vec<Ty<'tcx>>> {
+    match kind {
+        DefKind::Fn => {
+            let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+            Some(sig.inputs_and_output.to_vec())
+        },
+        DefKind::Const | DefKind::Static { .. } | DefKind::TyAlias => {
+            Some(vec![cx.tcx.type_of(def_id).instantiate_identity()])
+        },
+        _ => None,
+    }
+}
+
+/// The names of the ADTs mentioned in `tys` that aren't `pub`, sorted and deduplicated.
+fn private_types<'tcx>(cx: &LateContext<'tcx>, tys: Vec<Ty<'tcx>>) -> Vec<String> {
+    let mut private = tys
+        .into_iter()
+        .flat_map(Ty::walk)
+        .filter_map(|arg| match arg.unpack() {
+            GenericArgKind::Type(ty) => match ty.kind() {
+                ty::Adt(adt, _) if !cx.tcx.visibility(adt.did()).is_public() => {
+                    Some(format!("`{}`", cx.tcx.item_name(adt.did())))
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    private.sort_unstable();
+    private.dedup();
+    private
+}
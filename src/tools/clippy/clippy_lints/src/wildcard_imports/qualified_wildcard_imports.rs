@@ -0,0 +1,93 @@
+use super::QUALIFIED_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::source::snippet_opt;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{HirId, Item, ItemKind, Node, Path, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+/// Counts how the names of glob imported modules are used throughout the crate.
+#[derive(Default)]
+pub(super) struct QualifiedUsages {
+    globs: Vec<(HirId, Span, DefId)>,
+    qualified: FxHashMap<DefId, usize>,
+    unqualified: FxHashMap<DefId, usize>,
+}
+
+impl QualifiedUsages {
+    pub(super) fn check_glob(&mut self, item: &Item<'_>, use_path: &UsePath<'_>) {
+        // Glob imports always have a single resolution.
+        if let Res::Def(DefKind::Mod, module) = use_path.res[0]
+            && !use_path.span.is_empty()
+        {
+            self.globs.push((item.hir_id(), use_path.span, module));
+        }
+    }
+
+    pub(super) fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, hir_id: HirId) {
+        if path.span.from_expansion()
+            || matches!(
+                cx.tcx.hir_node(hir_id),
+                Node::Item(Item {
+                    kind: ItemKind::Use(..),
+                    ..
+                })
+            )
+        {
+            return;
+        }
+        match path.segments {
+            [.., parent, _] => {
+                if let Res::Def(DefKind::Mod, module) = parent.res {
+                    *self.qualified.entry(module).or_default() += 1;
+                }
+            },
+            [_] => {
+                if let Res::Def(_, def_id) = path.res
+                    && let Some(module) = parent_module(cx, def_id)
+                {
+                    *self.unqualified.entry(module).or_default() += 1;
+                }
+            },
+            [] => {},
+        }
+    }
+
+    pub(super) fn check_crate_post(&self, cx: &LateContext<'_>) {
+        for &(hir_id, span, module) in &self.globs {
+            let qualified = self.qualified.get(&module).copied().unwrap_or_default();
+            let unqualified = self.unqualified.get(&module).copied().unwrap_or_default();
+            if qualified > unqualified
+                && let Some(module_path) = snippet_opt(cx, span)
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    QUALIFIED_WILDCARD_IMPORTS,
+                    hir_id,
+                    span,
+                    "wildcard import from a module whose items are mostly used with a qualified path",
+                    |diag| {
+                        diag.note(format!(
+                            "found {qualified} qualified and {unqualified} unqualified usages of its items"
+                        ));
+                        diag.help(format!(
+                            "consider importing the module instead, e.g. `use {module_path};` or `use {module_path} as ..;`"
+                        ));
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn parent_module(cx: &LateContext<'_>, mut def_id: DefId) -> Option<DefId> {
+    while let Some(parent) = cx.tcx.opt_parent(def_id) {
+        if cx.tcx.def_kind(parent) == DefKind::Mod {
+            return Some(parent);
+        }
+        def_id = parent;
+    }
+    None
+}
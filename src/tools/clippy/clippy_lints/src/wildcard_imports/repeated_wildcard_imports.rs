@@ -0,0 +1,90 @@
+use super::{is_prelude_import, REPEATED_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalModDefId};
+use rustc_hir::{HirId, Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_middle::ty::print::with_crate_prefix;
+use rustc_span::Span;
+
+/// The number of modules which need to import the same module with a glob for it to be linted.
+const MIN_MODULES: usize = 3;
+
+struct Glob {
+    hir_id: HirId,
+    span: Span,
+    scope: LocalModDefId,
+}
+
+/// Returns the module imported by a private glob import and the module containing the import, unless
+/// the glob is one that is meant to be repeated across modules.
+pub(super) fn repeatable_glob(
+    cx: &LateContext<'_>,
+    item: &Item<'_>,
+    use_path: &UsePath<'_>,
+) -> Option<(DefId, LocalModDefId)> {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, module) = use_path.res[0] else {
+        return None;
+    };
+    let scope = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    // A `pub use` is a re-export rather than a duplicate, while preludes and sibling modules each
+    // importing their parent with `use super::*` are meant to be repeated.
+    if is_prelude_import(use_path.segments)
+        || cx.tcx.visibility(item.owner_id.def_id) != ty::Visibility::Restricted(scope.to_def_id())
+        || cx.tcx.opt_parent(scope.to_def_id()) == Some(module)
+    {
+        return None;
+    }
+    Some((module, scope))
+}
+
+/// Collects the private glob imports of the crate by the module they import from.
+#[derive(Default)]
+pub(super) struct RepeatedGlobs {
+    globs: FxIndexMap<DefId, Vec<Glob>>,
+}
+
+impl RepeatedGlobs {
+    pub(super) fn check_glob(&mut self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+        let Some((module, scope)) = repeatable_glob(cx, item, use_path) else {
+            return;
+        };
+        self.globs.entry(module).or_default().push(Glob {
+            hir_id: item.hir_id(),
+            span: item.span,
+            scope,
+        });
+    }
+
+    pub(super) fn check_crate_post(&self, cx: &LateContext<'_>) {
+        for (&module, globs) in &self.globs {
+            let modules = globs.iter().map(|glob| glob.scope).collect::<FxHashSet<_>>().len();
+            if modules < MIN_MODULES {
+                continue;
+            }
+            let [first, rest @ ..] = globs.as_slice() else {
+                continue;
+            };
+            span_lint_hir_and_then(
+                cx,
+                REPEATED_WILDCARD_IMPORTS,
+                first.hir_id,
+                first.span,
+                format!("the same wildcard import is repeated in {modules} modules"),
+                |diag| {
+                    diag.span_note(
+                        rest.iter().map(|glob| glob.span).collect::<Vec<_>>(),
+                        "also imported here",
+                    );
+                    diag.help(format!(
+                        "consider a single `pub(crate) use {}::*;` in a shared module, imported from there",
+                        with_crate_prefix!(cx.tcx.def_path_str(module))
+                    ));
+                },
+            );
+        }
+    }
+}
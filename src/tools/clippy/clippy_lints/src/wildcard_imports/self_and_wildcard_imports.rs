@@ -0,0 +1,66 @@
+use super::{without_explicit_imports, SELF_AND_WILDCARD_IMPORTS, WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_lint_allowed;
+use clippy_utils::source::snippet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, ItemKind, UseKind, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::symbol::kw;
+use rustc_span::BytePos;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // `wildcard_imports` already replaces the glob with the names it brings in.
+    if !is_lint_allowed(cx, WILDCARD_IMPORTS, item.hir_id()) {
+        return;
+    }
+    // Glob imports always have a single resolution.
+    let res @ Res::Def(DefKind::Mod, _) = use_path.res[0] else {
+        return;
+    };
+    // Only a glob inside braces, like `use foo::{self, *}`, has an empty path snippet.
+    if !snippet(cx, use_path.span, "..").is_empty() {
+        return;
+    }
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let uses = cx
+        .tcx
+        .hir()
+        .module_items(module)
+        .map(|id| cx.tcx.hir().item(id))
+        .filter_map(|use_item| match use_item.kind {
+            ItemKind::Use(path, kind) if !use_item.span.from_expansion() => Some((use_item.span, path, kind)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // Nested imports are lowered to one item each, next to a `ListStem` item for the braces around
+    // them, so the `self` import has to be in the same braces and name the glob's module.
+    let in_same_braces = uses.iter().any(|&(stem_span, _, kind)| {
+        kind == UseKind::ListStem
+            && stem_span.contains(item.span)
+            && uses.iter().any(|&(span, path, kind)| {
+                kind == UseKind::Single
+                    && path.res.contains(&res)
+                    && stem_span.contains(span)
+                    && snippet(cx, span, "..") == "self"
+            })
+    });
+    if !in_same_braces {
+        return;
+    }
+    let used_imports = without_explicit_imports(cx, item, res, cx.tcx.names_imported_by_glob_use(item.owner_id.def_id));
+    if !used_imports.contains(&kw::Underscore)
+        && let [name] = &used_imports.items().map(ToString::to_string).into_sorted_stable_ord()[..]
+    {
+        span_lint_and_sugg(
+            cx,
+            SELF_AND_WILDCARD_IMPORTS,
+            // The path of a glob inside braces ends right in front of the `*`.
+            use_path.span.with_hi(use_path.span.hi() + BytePos(1)),
+            "wildcard import next to `self` which only brings in a single name",
+            "try",
+            name.clone(),
+            Applicability::MachineApplicable,
+        );
+    }
+}
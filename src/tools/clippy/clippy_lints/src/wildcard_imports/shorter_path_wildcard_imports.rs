@@ -0,0 +1,71 @@
+use super::{module_children, SHORTER_PATH_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_crate_prefix;
+use rustc_span::symbol::kw;
+use std::iter;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    // The path of a glob nested in braces doesn't span the whole path of the module.
+    let path = snippet(cx, use_path.span, "..");
+    if path.is_empty() || snippet(cx, item.span, "..").contains('{') {
+        return;
+    }
+    let used = cx.tcx.names_imported_by_glob_use(item.owner_id.def_id);
+    if used.is_empty() || used.contains(&kw::Underscore) {
+        return;
+    }
+    let scope = cx.tcx.parent_module_from_def_id(item.owner_id.def_id).to_def_id();
+    let is_accessible = |module: DefId| {
+        iter::successors(Some(module), |&it| cx.tcx.opt_parent(it))
+            .take_while(|it| !it.is_crate_root())
+            .all(|it| cx.tcx.visibility(it).is_accessible_from(scope, cx.tcx))
+    };
+    let used_children = module_children(cx, def_id)
+        .iter()
+        .filter(|child| used.contains(&child.ident.name))
+        .collect::<Vec<_>>();
+    // The modules the glob's module is nested in, from the innermost one outward. The root of the
+    // crate is left out, as a glob importing all of it is hardly an improvement.
+    let Some(shorter) = iter::successors(cx.tcx.opt_parent(def_id), |&it| cx.tcx.opt_parent(it))
+        .take_while(|it| !it.is_crate_root())
+        .filter(|&module| cx.tcx.def_kind(module) == DefKind::Mod && is_accessible(module))
+        .filter(|&module| {
+            let children = module_children(cx, module);
+            used_children.iter().all(|used| {
+                children.iter().any(|child| {
+                    child.ident.name == used.ident.name
+                        && child.res == used.res
+                        && child.vis.is_accessible_from(scope, cx.tcx)
+                })
+            })
+        })
+        .last()
+    else {
+        return;
+    };
+    let shorter = with_crate_prefix!(cx.tcx.def_path_str(shorter));
+    if shorter.split("::").count() >= path.split("::").count() {
+        return;
+    }
+
+    span_lint_and_sugg(
+        cx,
+        SHORTER_PATH_WILDCARD_IMPORTS,
+        use_path.span,
+        "the names used from this wildcard import are also exported by a module with a shorter path",
+        "import from the shorter path",
+        shorter,
+        // The shorter path exports more names, which can clash with other imports of the module.
+        Applicability::MaybeIncorrect,
+    );
+}
@@ -0,0 +1,137 @@
+use super::SINGLE_FUNCTION_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::unord::UnordSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId, LocalModDefId};
+use rustc_hir::{HirId, Item, ItemKind, Node, Path, UsePath};
+use rustc_lint::LateContext;
+use rustc_span::{Span, Symbol};
+
+#[derive(Clone, Copy)]
+enum Usage {
+    Unused,
+    Function(LocalDefId),
+    Scattered,
+}
+
+struct Glob {
+    hir_id: HirId,
+    span: Span,
+    module: DefId,
+    scope: LocalModDefId,
+    names: UnordSet<Symbol>,
+    unseen: UnordSet<Symbol>,
+    usage: Usage,
+}
+
+/// Tracks which functions use the names brought in by module level glob imports.
+#[derive(Default)]
+pub(super) struct FunctionUsages {
+    globs: Vec<Glob>,
+}
+
+impl FunctionUsages {
+    pub(super) fn check_glob(&mut self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+        let scope = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+        // Glob imports always have a single resolution.
+        if let Res::Def(DefKind::Mod, module) = use_path.res[0]
+            && cx.tcx.local_parent(item.owner_id.def_id) == scope.to_local_def_id()
+        {
+            let names: UnordSet<Symbol> = cx
+                .tcx
+                .names_imported_by_glob_use(item.owner_id.def_id)
+                .items()
+                .copied()
+                .collect();
+            if !names.is_empty() {
+                self.globs.push(Glob {
+                    hir_id: item.hir_id(),
+                    span: item.span,
+                    module,
+                    scope,
+                    unseen: names.items().copied().collect(),
+                    names,
+                    usage: Usage::Unused,
+                });
+            }
+        }
+    }
+
+    pub(super) fn check_path(&mut self, cx: &LateContext<'_>, path: &Path<'_>, hir_id: HirId) {
+        if self.globs.is_empty()
+            || path.span.from_expansion()
+            || matches!(
+                cx.tcx.hir_node(hir_id),
+                Node::Item(Item {
+                    kind: ItemKind::Use(..),
+                    ..
+                })
+            )
+        {
+            return;
+        }
+        // Only the first segment of a path can be brought into scope by a glob.
+        let Some(first) = path.segments.first() else {
+            return;
+        };
+        let Res::Def(kind, def_id) = first.res else {
+            return;
+        };
+        let def_id = if let DefKind::Ctor(..) = kind {
+            cx.tcx.parent(def_id)
+        } else {
+            def_id
+        };
+        let Some(parent) = cx.tcx.opt_parent(def_id) else {
+            return;
+        };
+        let scope = cx.tcx.parent_module(hir_id);
+        let function = enclosing_function(cx, path.span);
+        for glob in &mut self.globs {
+            if glob.module == parent && glob.scope == scope && glob.names.contains(&first.ident.name) {
+                glob.unseen.remove(&first.ident.name);
+                glob.usage = match (glob.usage, function) {
+                    (Usage::Unused, Some(function)) => Usage::Function(function),
+                    (Usage::Function(previous), Some(function)) if previous == function => glob.usage,
+                    _ => Usage::Scattered,
+                };
+            }
+        }
+    }
+
+    pub(super) fn check_crate_post(&self, cx: &LateContext<'_>) {
+        for glob in &self.globs {
+            // If some of the names were never seen, they are used in a way we don't track, e.g. through a
+            // re-export, so we can't tell where they are used.
+            if let Usage::Function(function) = glob.usage
+                && glob.unseen.is_empty()
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    SINGLE_FUNCTION_WILDCARD_IMPORTS,
+                    glob.hir_id,
+                    glob.span,
+                    "wildcard import is only used inside a single function",
+                    |diag| {
+                        diag.span_help(
+                            cx.tcx.def_span(function),
+                            "consider moving the import into this function",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the function whose body contains `span`, looking through closures. Paths in the
+/// signature of a function don't count, since an import inside the body doesn't cover them.
+fn enclosing_function(cx: &LateContext<'_>, span: Span) -> Option<LocalDefId> {
+    let body = cx.enclosing_body?;
+    if !cx.tcx.hir().body(body).value.span.contains(span) {
+        return None;
+    }
+    let owner = cx.tcx.hir().body_owner_def_id(body);
+    let root = cx.tcx.typeck_root_def_id(owner.to_def_id()).expect_local();
+    matches!(cx.tcx.def_kind(root), DefKind::Fn | DefKind::AssocFn).then_some(root)
+}
@@ -0,0 +1,46 @@
+use super::{is_prelude_import, module_children, TRAIT_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LOCAL_CRATE;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    // Preludes exist to bring traits into scope with a glob.
+    if is_prelude_import(use_path.segments) {
+        return;
+    }
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    // Traits of the current crate change together with the code calling their methods, so only
+    // the ones defined in dependencies are a concern.
+    let mut traits = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        .filter(|child| matches!(child.res, Res::Def(DefKind::Trait, trait_id) if trait_id.krate != LOCAL_CRATE))
+        .map(|child| format!("`{}`", child.ident))
+        .collect::<Vec<_>>();
+    if traits.is_empty() {
+        return;
+    }
+    traits.sort_unstable();
+
+    span_lint_and_then(
+        cx,
+        TRAIT_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings traits of another crate into scope",
+        |diag| {
+            diag.note(format!(
+                "methods of {} can be called without naming the trait{}",
+                traits.join(", "),
+                if traits.len() == 1 { "" } else { "s" }
+            ));
+            diag.note("a method added in a later version can change which method a call resolves to");
+            diag.help("import the traits that are used by name");
+        },
+    );
+}
@@ -0,0 +1,50 @@
+use super::TRANSITIVE_WILDCARD_IMPORTS;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalModDefId;
+use rustc_hir::{Item, ItemKind, UseKind, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let Some(local_id) = def_id.as_local() else {
+        return;
+    };
+    if !cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+        return;
+    }
+
+    // Only the public globs of the module are re-exported as public again. The others are either
+    // not re-exported at all, or with their own, more restricted visibility.
+    let spans = cx
+        .tcx
+        .hir()
+        .module_items(LocalModDefId::new_unchecked(local_id))
+        .map(|id| cx.tcx.hir().item(id))
+        .filter(|inner| {
+            matches!(inner.kind, ItemKind::Use(_, UseKind::Glob))
+                && !inner.span.from_expansion()
+                && cx.tcx.visibility(inner.owner_id.def_id).is_public()
+        })
+        .map(|inner| inner.span)
+        .collect::<Vec<_>>();
+    if spans.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        TRANSITIVE_WILDCARD_IMPORTS,
+        use_path.span,
+        format!(
+            "this wildcard re-export also re-exports the wildcard imports of `{}`",
+            cx.tcx.def_path_str(def_id)
+        ),
+        |diag| {
+            diag.span_note(spans, "the items imported here become part of the public API as well");
+        },
+    );
+}
@@ -0,0 +1,45 @@
+use super::{module_children, TYPE_AND_VALUE_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Namespace, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    let visible = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx));
+
+    let types = visible
+        .clone()
+        .filter(|child| child.res.ns() == Some(Namespace::TypeNS))
+        .map(|child| child.ident.name)
+        .collect::<FxHashSet<_>>();
+    let mut shared = visible
+        .filter(|child| child.res.ns() == Some(Namespace::ValueNS) && types.contains(&child.ident.name))
+        .map(|child| format!("`{}`", child.ident))
+        .collect::<Vec<_>>();
+    if shared.is_empty() {
+        return;
+    }
+    shared.sort_unstable();
+    shared.dedup();
+
+    span_lint_and_then(
+        cx,
+        TYPE_AND_VALUE_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings in a type and a value with the same name",
+        |diag| {
+            diag.note(format!(
+                "these names refer to both a type and a value: {}",
+                shared.join(", ")
+            ));
+        },
+    );
+}
@@ -0,0 +1,40 @@
+use super::{module_children, UNSTABLE_WILDCARD_IMPORTS};
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+    // Glob imports always have a single resolution.
+    let Res::Def(DefKind::Mod, def_id) = use_path.res[0] else {
+        return;
+    };
+    let module = cx.tcx.parent_module_from_def_id(item.owner_id.def_id);
+    // Unstable items can only be used once their feature is enabled, so the others don't tie the
+    // crate to nightly.
+    let mut unstable = module_children(cx, def_id)
+        .iter()
+        .filter(|child| child.vis.is_accessible_from(module.to_def_id(), cx.tcx))
+        .filter_map(|child| {
+            let stability = cx.tcx.lookup_stability(child.res.opt_def_id()?)?;
+            (stability.is_unstable() && cx.tcx.features().declared(stability.feature))
+                .then(|| format!("`{}` (feature `{}`)", child.ident, stability.feature))
+        })
+        .collect::<Vec<_>>();
+    if unstable.is_empty() {
+        return;
+    }
+    unstable.sort_unstable();
+    unstable.dedup();
+
+    span_lint_and_then(
+        cx,
+        UNSTABLE_WILDCARD_IMPORTS,
+        use_path.span,
+        "wildcard import brings unstable items into scope",
+        |diag| {
+            diag.note(format!("these items are unstable: {}", unstable.join(", ")));
+            diag.help("import the unstable items that are used by name, to make the dependency on nightly visible");
+        },
+    );
+}
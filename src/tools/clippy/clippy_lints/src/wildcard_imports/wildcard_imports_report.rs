@@ -0,0 +1,70 @@
+use super::WILDCARD_IMPORTS_REPORT;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_hir::hir_id::CRATE_HIR_ID;
+use rustc_hir::{Item, UsePath};
+use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_crate_prefix;
+use rustc_span::Span;
+
+struct Entry {
+    span: Span,
+    path: String,
+    names: Vec<String>,
+}
+
+/// Collects all glob imports of the crate together with the names used through them, to report them
+/// at once after the crate was checked.
+#[derive(Default)]
+pub(super) struct GlobReport {
+    entries: Vec<Entry>,
+}
+
+impl GlobReport {
+    pub(super) fn check_glob(&mut self, cx: &LateContext<'_>, item: &Item<'_>, use_path: &UsePath<'_>) {
+        // Glob imports always have a single resolution.
+        let Some(def_id) = use_path.res[0].opt_def_id() else {
+            return;
+        };
+        let names = cx
+            .tcx
+            .names_imported_by_glob_use(item.owner_id.def_id)
+            .items()
+            .map(ToString::to_string)
+            .into_sorted_stable_ord();
+        self.entries.push(Entry {
+            span: item.span,
+            path: with_crate_prefix!(cx.tcx.def_path_str(def_id)),
+            names,
+        });
+    }
+
+    pub(super) fn check_crate_post(&self, cx: &LateContext<'_>) {
+        if self.entries.is_empty() {
+            return;
+        }
+        span_lint_hir_and_then(
+            cx,
+            WILDCARD_IMPORTS_REPORT,
+            CRATE_HIR_ID,
+            self.entries.iter().map(|entry| entry.span).collect::<Vec<_>>(),
+            format!(
+                "report of {} wildcard import{}",
+                self.entries.len(),
+                if self.entries.len() == 1 { "" } else { "s" }
+            ),
+            |diag| {
+                for entry in &self.entries {
+                    let loc = cx.sess().source_map().lookup_char_pos(entry.span.lo());
+                    diag.note(format!(
+                        "{}:{}:{}: {}::{{{}}}",
+                        loc.file.name.prefer_local(),
+                        loc.line,
+                        loc.col_display + 1,
+                        entry.path,
+                        entry.names.join(", ")
+                    ));
+                }
+            },
+        );
+    }
+}
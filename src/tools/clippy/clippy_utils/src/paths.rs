@@ -51,6 +51,10 @@
 pub const LATE_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "LateLintPass"];
 pub const LINT: [&str; 2] = ["rustc_lint_defs", "Lint"];
 pub const MSRV: [&str; 3] = ["clippy_config", "msrvs", "Msrv"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_SYNC_LAZY: [&str; 3] = ["once_cell", "sync", "Lazy"];
+#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
+pub const ONCE_CELL_UNSYNC_LAZY: [&str; 3] = ["once_cell", "unsync", "Lazy"];
 pub const OPEN_OPTIONS_NEW: [&str; 4] = ["std", "fs", "OpenOptions", "new"];
 pub const OS_STRING_AS_OS_STR: [&str; 5] = ["std", "ffi", "os_str", "OsString", "as_os_str"];
 pub const OS_STR_TO_OS_STRING: [&str; 5] = ["std", "ffi", "os_str", "OsStr", "to_os_string"];
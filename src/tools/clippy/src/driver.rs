@@ -16,8 +16,10 @@
 extern crate rustc_session;
 extern crate rustc_span;
 
+use clippy_config::types::ClippyLintLevel;
 use rustc_interface::interface;
 use rustc_session::config::ErrorOutputType;
+use rustc_session::lint::Level;
 use rustc_session::parse::ParseSess;
 use rustc_session::EarlyDiagCtxt;
 use rustc_span::symbol::Symbol;
@@ -129,6 +131,18 @@ impl rustc_driver::Callbacks for ClippyCallbacks {
     #[allow(rustc::bad_opt_access)]
     fn config(&mut self, config: &mut interface::Config) {
         let conf_path = clippy_config::lookup_conf_file();
+        if let Some(level) = clippy_config::read_wildcard_imports_severity(&conf_path) {
+            let level = match level {
+                ClippyLintLevel::Allow => Level::Allow,
+                ClippyLintLevel::Warn => Level::Warn,
+                ClippyLintLevel::Deny => Level::Deny,
+            };
+            // Command line flags are applied in order, so the ones passed by the user still override this.
+            config
+                .opts
+                .lint_opts
+                .insert(0, ("clippy::wildcard_imports".to_owned(), level));
+        }
         let previous = config.register_lints.take();
         let clippy_args_var = self.clippy_args_var.take();
         config.psess_created = Some(Box::new(move |psess| {
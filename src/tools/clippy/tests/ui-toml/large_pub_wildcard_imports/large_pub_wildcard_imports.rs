@@ -0,0 +1,30 @@
+#![warn(clippy::large_pub_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod small {
+    // A unit struct is re-exported in both namespaces, but only counts once.
+    pub struct Unit;
+    pub struct Record {}
+    pub fn function() {}
+    fn private() {}
+}
+
+mod large {
+    pub struct First;
+    pub struct Second;
+    pub struct Third;
+    pub fn fourth() {}
+}
+
+// Allowed: exactly `pub-wildcard-max-reexports` items.
+pub use small::*;
+
+pub use large::*;
+//~^ ERROR: this wildcard re-export adds 4 items to the public API, exceeding the maximum of 3
+
+mod private {
+    // Allowed: not part of the public API.
+    pub use crate::large::*;
+}
+
+fn main() {}
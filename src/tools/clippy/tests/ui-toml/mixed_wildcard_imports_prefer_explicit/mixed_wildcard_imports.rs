@@ -0,0 +1,46 @@
+#![warn(clippy::mixed_wildcard_imports)]
+#![allow(unused_imports, dead_code)]
+
+mod mixed {
+    use std::collections::*;
+    //~^ ERROR: wildcard import from `std::collections`, which is also imported by name
+    use std::collections::HashMap;
+}
+
+mod different_modules {
+    use std::collections::*;
+    use std::fmt::Debug;
+}
+
+mod globs_only {
+    use std::collections::*;
+    use std::fmt::*;
+}
+
+mod names_only {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+}
+
+mod prelude {
+    use std::io::prelude::*;
+    use std::io::Cursor;
+}
+
+mod different_crates {
+    use core::cell::*;
+    use std::fmt::Debug;
+}
+
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+}
+
+mod local {
+    use crate::shapes::*;
+    //~^ ERROR: wildcard import from `shapes`, which is also imported by name
+    use crate::shapes::Circle;
+}
+
+fn main() {}
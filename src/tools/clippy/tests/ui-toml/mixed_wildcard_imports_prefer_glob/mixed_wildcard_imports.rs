@@ -0,0 +1,46 @@
+#![warn(clippy::mixed_wildcard_imports)]
+#![allow(unused_imports, dead_code)]
+
+mod mixed {
+    use std::collections::*;
+    use std::collections::HashMap;
+    //~^ ERROR: import by name from `std::collections`, which is also imported with a wildcard
+}
+
+mod different_modules {
+    use std::collections::*;
+    use std::fmt::Debug;
+}
+
+mod globs_only {
+    use std::collections::*;
+    use std::fmt::*;
+}
+
+mod names_only {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+}
+
+mod prelude {
+    use std::io::prelude::*;
+    use std::io::Cursor;
+}
+
+mod different_crates {
+    use core::cell::*;
+    use std::fmt::Debug;
+}
+
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+}
+
+mod local {
+    use crate::shapes::*;
+    use crate::shapes::Circle;
+    //~^ ERROR: import by name from `shapes`, which is also imported with a wildcard
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+//@no-rustfix
+#![warn(clippy::wildcard_imports)]
+
+mod items {
+    pub fn item() {}
+}
+
+// Allowed: `autogen` is a configured pattern.
+#[path = "auxiliary/autogen.rs"]
+mod autogen;
+
+// Not allowed: the configuration replaces the default patterns.
+#[path = "auxiliary/ffi.rs"]
+mod ffi;
+
+fn main() {
+    autogen::call();
+    ffi::call();
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::wildcard_imports)]
+
+mod utils {
+    pub fn print() {}
+}
+
+// Every expansion imports through the same glob of the macro definition, which is reported once.
+macro_rules! printing_module {
+    ($name:ident) => {
+        mod $name {
+            use crate::utils::*;
+            //~^ ERROR: usage of wildcard import
+
+            pub fn run() {
+                print();
+            }
+        }
+    };
+}
+
+printing_module!(first);
+printing_module!(second);
+
+fn main() {
+    first::run();
+    second::run();
+}
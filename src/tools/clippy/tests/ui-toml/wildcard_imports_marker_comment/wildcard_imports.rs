@@ -0,0 +1,29 @@
+#![warn(clippy::wildcard_imports)]
+
+mod generated {
+    pub fn item() {}
+}
+
+mod configured {
+    // generated: keep glob
+    use crate::generated::*;
+
+    pub fn call() {
+        item();
+    }
+}
+
+mod default_marker {
+    // clippy:wildcard-ok
+    use crate::generated::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn call() {
+        item();
+    }
+}
+
+fn main() {
+    configured::call();
+    default_marker::call();
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::wildcard_imports)]
+
+mod utils {
+    pub fn print() {}
+    pub fn debug() {}
+}
+
+// Not allowed: this file has at least `wildcard-imports-min-file-lines` lines.
+use utils::*;
+//~^ ERROR: usage of wildcard import
+
+fn first() {
+    print();
+}
+
+fn second() {
+    debug();
+}
+
+fn main() {
+    first();
+    second();
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::wildcard_imports)]
+
+mod utils {
+    pub fn print() {}
+}
+
+// Allowed: this file is shorter than `wildcard-imports-min-file-lines`.
+use utils::*;
+
+fn main() {
+    print();
+}
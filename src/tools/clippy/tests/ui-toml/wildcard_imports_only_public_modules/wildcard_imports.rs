@@ -0,0 +1,32 @@
+#![warn(clippy::wildcard_imports)]
+
+pub mod items {
+    pub fn item() {}
+}
+
+pub mod public {
+    use crate::items::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn call() {
+        item();
+    }
+}
+
+mod private_impl {
+    use crate::items::*;
+
+    pub fn call() {
+        item();
+    }
+
+    pub mod nested {
+        pub use crate::items::*;
+    }
+}
+
+fn main() {
+    public::call();
+    private_impl::call();
+    private_impl::nested::item();
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+#![warn(clippy::wildcard_imports_report)]
+
+mod shapes {
+    pub struct Circle(pub f64);
+    pub struct Square(pub f64);
+
+    pub fn unit() -> Square {
+        Square(1.0)
+    }
+}
+
+mod colors {
+    pub enum Color {
+        Red,
+        Green,
+    }
+}
+
+use colors::Color::*;
+//~^ ERROR: report of 2 wildcard imports
+use shapes::*;
+
+fn main() {
+    let _ = (Red, unit(), Circle(1.0));
+}
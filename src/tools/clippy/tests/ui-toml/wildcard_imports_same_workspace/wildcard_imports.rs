@@ -0,0 +1,16 @@
+#![warn(clippy::wildcard_imports)]
+
+mod utils {
+    pub fn print() {}
+}
+
+// Allowed: the current crate is part of the workspace.
+use utils::*;
+// Not allowed: `std` is not part of the workspace.
+use std::collections::*;
+//~^ ERROR: usage of wildcard import
+
+fn main() {
+    print();
+    let _ = HashMap::<u8, u8>::new();
+}
@@ -0,0 +1,21 @@
+// `wildcard_imports` is enabled without `clippy::pedantic`.
+
+mod utils {
+    pub fn print() {}
+}
+
+mod other {
+    pub fn other_print() {}
+}
+
+use utils::*;
+//~^ ERROR: usage of wildcard import
+
+// Lint attributes still take precedence over the configured level.
+#[allow(clippy::wildcard_imports)]
+use other::*;
+
+fn main() {
+    print();
+    other_print();
+}
@@ -0,0 +1,18 @@
+extern crate proc_macro;
+
+use proc_macro::{TokenStream, TokenTree};
+
+/// Like the `test-case` crate, puts a module with a `use super::*` next to the function, which calls
+/// it with the given arguments.
+#[proc_macro_attribute]
+pub fn test_case(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut tokens = input.clone().into_iter();
+    let name = tokens
+        .by_ref()
+        .find(|tt| matches!(tt, TokenTree::Ident(ident) if ident.to_string() == "fn"))
+        .and_then(|_| tokens.next())
+        .expect("expected a function");
+    format!("{input} mod {name} {{ use super::*; fn case() {{ {name}({args}); }} }}")
+        .parse()
+        .unwrap()
+}
@@ -0,0 +1,25 @@
+//@aux-build:test_case.rs
+
+#![warn(clippy::wildcard_imports)]
+#![allow(dead_code)]
+
+extern crate test_case;
+
+use test_case::test_case;
+
+mod utils {
+    pub fn double(x: u32) -> u32 {
+        x * 2
+    }
+}
+
+// Allowed: the `use super::*` generated by the attribute isn't linted.
+#[test_case(1)]
+fn doubles(x: u32) {
+    let _ = double(x);
+}
+
+use utils::*;
+//~^ ERROR: usage of wildcard import
+
+fn main() {}
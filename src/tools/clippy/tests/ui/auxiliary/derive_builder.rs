@@ -0,0 +1,15 @@
+extern crate proc_macro;
+
+use proc_macro::{TokenStream, TokenTree};
+
+/// Generates an empty `<Name>Builder` struct next to the annotated item.
+#[proc_macro_derive(Builder)]
+pub fn derive_builder(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let name = tokens
+        .by_ref()
+        .find(|tt| matches!(tt, TokenTree::Ident(ident) if ident.to_string() == "struct"))
+        .and_then(|_| tokens.next())
+        .expect("expected a struct");
+    format!("struct {name}Builder;").parse().unwrap()
+}
@@ -0,0 +1,31 @@
+//! A minimal stand-in for the `lazy_static` crate, keeping the shape of its expansion.
+
+#[macro_export]
+macro_rules! lazy_static {
+    ($(#[$attr:meta])* static ref $name:ident : $t:ty = $init:expr;) => {
+        $crate::__lazy_static_internal!($(#[$attr])* static ref $name : $t = $init;);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __lazy_static_internal {
+    ($(#[$attr:meta])* static ref $name:ident : $t:ty = $init:expr;) => {
+        #[allow(non_camel_case_types)]
+        $(#[$attr])*
+        struct $name {
+            __private_field: (),
+        }
+        static $name: $name = $name { __private_field: () };
+        impl ::std::ops::Deref for $name {
+            type Target = $t;
+            fn deref(&self) -> &$t {
+                fn __static_ref_initialize() -> $t {
+                    $init
+                }
+                static LAZY: ::std::sync::OnceLock<$t> = ::std::sync::OnceLock::new();
+                LAZY.get_or_init(__static_ref_initialize)
+            }
+        }
+    };
+}
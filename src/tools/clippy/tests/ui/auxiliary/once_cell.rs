@@ -0,0 +1,55 @@
+//! A minimal stand-in for the `once_cell` crate, providing its `Lazy` types.
+
+pub mod sync {
+    use std::ops::Deref;
+    use std::sync::OnceLock;
+
+    pub struct Lazy<T, F = fn() -> T> {
+        cell: OnceLock<T>,
+        init: F,
+    }
+
+    impl<T, F> Lazy<T, F> {
+        pub const fn new(init: F) -> Self {
+            Self {
+                cell: OnceLock::new(),
+                init,
+            }
+        }
+    }
+
+    impl<T, F: Fn() -> T> Deref for Lazy<T, F> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.cell.get_or_init(&self.init)
+        }
+    }
+}
+
+pub mod unsync {
+    use std::cell::OnceCell;
+    use std::ops::Deref;
+
+    pub struct Lazy<T, F = fn() -> T> {
+        cell: OnceCell<T>,
+        init: F,
+    }
+
+    impl<T, F> Lazy<T, F> {
+        pub const fn new(init: F) -> Self {
+            Self {
+                cell: OnceCell::new(),
+                init,
+            }
+        }
+    }
+
+    impl<T, F: Fn() -> T> Deref for Lazy<T, F> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            self.cell.get_or_init(&self.init)
+        }
+    }
+}
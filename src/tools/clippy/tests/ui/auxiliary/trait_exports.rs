@@ -0,0 +1,39 @@
+pub mod shapes {
+    pub trait Area {
+        fn area(&self) -> f64;
+    }
+
+    pub trait Perimeter {
+        fn perimeter(&self) -> f64;
+    }
+
+    pub struct Square(pub f64);
+
+    impl Area for Square {
+        fn area(&self) -> f64 {
+            self.0 * self.0
+        }
+    }
+
+    impl Perimeter for Square {
+        fn perimeter(&self) -> f64 {
+            4.0 * self.0
+        }
+    }
+}
+
+pub mod sized {
+    pub trait Volume {
+        fn volume(&self) -> f64;
+    }
+
+    pub struct Cube(pub f64);
+}
+
+pub mod plain {
+    pub struct Point;
+}
+
+pub mod prelude {
+    pub use crate::shapes::{Area, Perimeter};
+}
@@ -0,0 +1,23 @@
+#![feature(staged_api)]
+#![stable(feature = "unstable_exports", since = "1.0.0")]
+
+#[stable(feature = "unstable_exports", since = "1.0.0")]
+pub mod shiny {
+    #[stable(feature = "unstable_exports", since = "1.0.0")]
+    pub fn polish() {}
+
+    #[unstable(feature = "shiny_gloss", issue = "none")]
+    pub fn gloss() {}
+
+    #[unstable(feature = "shiny_gloss", issue = "none")]
+    pub struct Gloss;
+}
+
+#[stable(feature = "unstable_exports", since = "1.0.0")]
+pub mod dull {
+    #[stable(feature = "unstable_exports", since = "1.0.0")]
+    pub fn plain() {}
+
+    #[unstable(feature = "dull_matte", issue = "none")]
+    pub fn matte() {}
+}
@@ -0,0 +1,35 @@
+#![warn(clippy::builtin_macro_wildcard_imports)]
+#![allow(clippy::wildcard_imports)]
+
+mod my_macros {
+    macro_rules! vec {
+        () => {
+            0
+        };
+    }
+    pub(crate) use vec;
+
+    macro_rules! my_vec {
+        () => {
+            0
+        };
+    }
+    pub(crate) use my_vec;
+
+    pub fn helper() {}
+}
+
+mod no_macros {
+    pub fn vec() {}
+}
+
+use my_macros::*;
+//~^ ERROR: wildcard import brings in a macro with the same name as a built-in macro
+// Only macros can clash with built-in macros.
+use no_macros::*;
+
+fn main() {
+    helper();
+    let _ = my_vec!();
+    vec();
+}
@@ -0,0 +1,32 @@
+#![warn(clippy::case_colliding_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod colliding {
+    pub struct Foo;
+    pub fn foo() {}
+}
+
+mod several {
+    pub struct Bar;
+    pub fn bar() {}
+    #[allow(non_upper_case_globals)]
+    pub const bAR: u32 = 0;
+    pub struct Baz;
+    pub fn baz() {}
+}
+
+mod distinct {
+    pub struct Unit;
+    pub struct Tuple(pub u32);
+    pub fn function() {}
+    // Not visible to the glob at all.
+    fn unit() {}
+}
+
+use colliding::*;
+//~^ ERROR: wildcard import brings in names that differ only by case
+use several::*;
+//~^ ERROR: wildcard import brings in names that differ only by case
+use distinct::*;
+
+fn main() {}
@@ -0,0 +1,49 @@
+//@compile-flags: --cfg feature="enabled"
+#![warn(clippy::cfg_dependent_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod gated {
+    #[cfg(feature = "enabled")]
+    pub fn enabled() {}
+    #[cfg(feature = "disabled")]
+    pub fn disabled() {}
+    pub fn always() {}
+}
+
+mod nested_predicate {
+    #[cfg(all(unix, not(feature = "disabled")))]
+    pub struct NotDisabled;
+}
+
+mod test_only {
+    #[cfg(test)]
+    pub fn helper() {}
+    pub fn always() {}
+}
+
+mod ungated {
+    pub fn always() {}
+}
+
+mod reexport {
+    pub use super::gated::{always, enabled};
+}
+
+mod imports {
+    use crate::gated::*;
+    //~^ ERROR: wildcard import from a module whose exports depend on enabled features
+}
+
+mod nested_imports {
+    use crate::nested_predicate::*;
+    //~^ ERROR: wildcard import from a module whose exports depend on enabled features
+}
+
+// Only the module defining the gated items is linted.
+mod unlinted_imports {
+    use crate::reexport::*;
+    use crate::test_only::*;
+    use crate::ungated::*;
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![crate_type = "lib"]
+#![warn(clippy::crate_api_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod internal {
+    pub struct Client;
+    pub fn connect() -> Client {
+        Client
+    }
+}
+
+mod helpers {
+    pub(crate) fn log() {}
+}
+
+pub use internal::*;
+//~^ ERROR: the public API of this crate is defined by a single wildcard re-export
+use helpers::log;
@@ -0,0 +1,16 @@
+#![crate_type = "lib"]
+#![warn(clippy::crate_api_wildcard_imports)]
+
+mod internal {
+    pub struct Client;
+    pub fn connect() -> Client {
+        Client
+    }
+}
+
+// The crate root also exports other items, so the glob is not the whole API.
+pub use internal::*;
+
+pub mod config {
+    pub struct Config;
+}
@@ -0,0 +1,25 @@
+//@aux-build:derive_builder.rs
+#![warn(clippy::derive_colliding_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+extern crate derive_builder;
+
+use derive_builder::Builder;
+
+mod builders {
+    pub struct ConfigBuilder;
+    pub struct OtherBuilder;
+}
+
+mod unrelated {
+    pub struct Unrelated;
+}
+
+use builders::*;
+//~^ ERROR: wildcard import brings in names that a derive macro generates items for
+use unrelated::*;
+
+#[derive(Builder)]
+struct Config;
+
+fn main() {}
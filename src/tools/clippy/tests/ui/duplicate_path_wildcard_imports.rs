@@ -0,0 +1,32 @@
+#![crate_type = "lib"]
+#![warn(clippy::duplicate_path_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+pub mod shapes {
+    pub struct Circle;
+    pub fn area() {}
+}
+
+pub mod partly {
+    pub struct Shown;
+    pub(crate) struct Internal;
+}
+
+mod private {
+    pub struct Hidden;
+}
+
+pub mod other {
+    pub struct Other;
+}
+
+pub use shapes::*;
+//~^ ERROR: public wildcard re-export makes items reachable through more than one public path
+pub use partly::*;
+//~^ ERROR: public wildcard re-export makes items reachable through more than one public path
+
+// The module itself is private, so the re-export is the only public path.
+pub use private::*;
+
+// The re-export isn't public.
+pub(crate) use other::*;
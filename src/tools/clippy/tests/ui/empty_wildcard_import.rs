@@ -0,0 +1,47 @@
+#![warn(clippy::empty_wildcard_import)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod internal {
+    fn helper() {}
+    struct Secret;
+}
+
+mod nested {
+    mod hidden {
+        pub(super) fn helper() {}
+    }
+}
+
+mod shapes {
+    pub struct Square;
+    pub(crate) struct Circle;
+}
+
+mod empty {}
+
+use internal::*;
+//~^ ERROR: this wildcard import has no effect, as `internal` has no items visible here
+
+use empty::*;
+//~^ ERROR: this wildcard import has no effect, as `empty` has no items visible here
+
+// `hidden` itself is private to `nested`.
+use nested::*;
+//~^ ERROR: this wildcard import has no effect, as `nested` has no items visible here
+
+use shapes::*;
+
+mod child {
+    // Private items of the parent module are visible here.
+    use super::*;
+}
+
+// Everything in `platform` is compiled out here, but the import is needed where it isn't.
+mod platform {
+    #[cfg(any())]
+    pub fn open() {}
+}
+
+use platform::*;
+
+fn main() {}
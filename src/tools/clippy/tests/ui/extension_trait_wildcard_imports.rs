@@ -0,0 +1,53 @@
+#![warn(clippy::extension_trait_wildcard_imports)]
+#![allow(dead_code, clippy::wildcard_imports)]
+
+mod ext {
+    pub trait Shout {
+        fn shout(&self) -> String;
+    }
+
+    impl Shout for str {
+        fn shout(&self) -> String {
+            self.to_uppercase()
+        }
+    }
+
+    pub struct Loud(pub String);
+}
+
+mod only_methods {
+    use crate::ext::*;
+    //~^ ERROR: wildcard import is only used to bring extension trait methods into scope
+
+    pub fn greet() -> String {
+        "hello".shout()
+    }
+}
+
+// The glob also brings in a struct that is used by name.
+mod with_types {
+    use crate::ext::*;
+
+    pub fn greet() -> Loud {
+        Loud("hello".shout())
+    }
+}
+
+mod prelude {
+    pub use crate::ext::Shout;
+}
+
+// Preludes are meant to be imported with a glob.
+mod prelude_user {
+    use crate::prelude::*;
+
+    pub fn greet() -> String {
+        "hello".shout()
+    }
+}
+
+fn main() {
+    only_methods::greet();
+    with_types::greet();
+    prelude_user::greet();
+}
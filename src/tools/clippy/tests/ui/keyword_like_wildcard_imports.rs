@@ -0,0 +1,27 @@
+#![warn(clippy::keyword_like_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod raw {
+    #[allow(non_camel_case_types)]
+    pub struct r#type;
+    pub fn r#match() {}
+}
+
+mod contextual {
+    pub fn union() {}
+    pub struct Regular;
+}
+
+mod plain {
+    pub fn regular() {}
+    // Not visible to the glob at all.
+    fn r#type() {}
+}
+
+use raw::*;
+//~^ ERROR: wildcard import brings in names that are easy to mistake for keywords
+use contextual::*;
+//~^ ERROR: wildcard import brings in names that are easy to mistake for keywords
+use plain::*;
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::masked_unused_wildcard_imports)]
+#![allow(dead_code, clippy::wildcard_imports)]
+
+mod shapes {
+    pub struct Square;
+    pub struct Circle;
+}
+
+mod dead {
+    #[allow(unused_imports)]
+    use crate::shapes::*;
+    //~^ ERROR: this wildcard import is unused, but an `#[allow]` hides the warning
+}
+
+mod dead_through_group {
+    #[allow(unused)]
+    use crate::shapes::*;
+    //~^ ERROR: this wildcard import is unused, but an `#[allow]` hides the warning
+}
+
+// The glob is used, so the attribute doesn't hide anything.
+mod used {
+    #[allow(unused_imports)]
+    use crate::shapes::*;
+
+    pub fn square() -> Square {
+        Square
+    }
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+#![warn(clippy::param_shadowed_wildcard_imports)]
+#![allow(clippy::wildcard_imports, dead_code)]
+
+mod settings {
+    pub struct Config;
+
+    pub fn config() -> Config {
+        Config
+    }
+
+    pub fn defaults() -> Config {
+        Config
+    }
+}
+
+mod shadowed {
+    use crate::settings::*;
+    //~^ ERROR: wildcard import brings in functions that are shadowed by function parameters
+
+    fn apply(config: Config) -> Config {
+        config
+    }
+
+    fn fresh() -> Config {
+        config()
+    }
+}
+
+mod not_shadowed {
+    use crate::settings::*;
+
+    fn apply(settings: Config) -> Config {
+        let _ = defaults();
+        settings
+    }
+}
+
+mod explicitly_imported {
+    use crate::settings::config;
+    use crate::settings::*;
+
+    fn apply(config: Config) -> Config {
+        config
+    }
+
+    fn fresh() -> Config {
+        config()
+    }
+}
+
+fn main() {}
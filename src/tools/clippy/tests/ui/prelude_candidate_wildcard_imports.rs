@@ -0,0 +1,64 @@
+#![warn(clippy::prelude_candidate_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod shapes {
+    pub struct Circle;
+}
+
+mod colors {
+    pub struct Red;
+}
+
+mod a {
+    use crate::colors::*;
+    //~^ ERROR: the same 2 wildcard imports are repeated in 3 modules
+    use crate::shapes::*;
+
+    pub fn red_circle() -> (Red, Circle) {
+        (Red, Circle)
+    }
+}
+
+mod b {
+    // The order of the imports doesn't matter.
+    use crate::shapes::*;
+    use crate::colors::*;
+
+    pub fn circle() -> Circle {
+        Circle
+    }
+}
+
+mod c {
+    use crate::colors::*;
+    use crate::shapes::*;
+
+    pub fn red() -> Red {
+        Red
+    }
+}
+
+// Importing only one of the modules, or one more, is a different set.
+mod sizes {
+    pub struct Large;
+}
+
+mod d {
+    use crate::shapes::*;
+
+    pub fn circle() -> Circle {
+        Circle
+    }
+}
+
+mod e {
+    use crate::colors::*;
+    use crate::shapes::*;
+    use crate::sizes::*;
+
+    pub fn large() -> Large {
+        Large
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+#![crate_type = "lib"]
+#![warn(clippy::private_interface_wildcard_imports)]
+#![allow(dead_code, private_interfaces, clippy::wildcard_imports)]
+
+mod inner {
+    pub(crate) struct Secret;
+    pub struct Open;
+
+    pub fn make_secret() -> Secret {
+        Secret
+    }
+    pub fn take_secret(_: Vec<Secret>) {}
+    pub fn make_open() -> Open {
+        Open
+    }
+    pub const SECRET: Option<Secret> = None;
+    pub static OPEN: Open = Open;
+}
+
+mod clean {
+    pub struct Shape;
+    pub fn make_shape() -> Shape {
+        Shape
+    }
+}
+
+pub use inner::*;
+//~^ ERROR: wildcard re-export includes items that expose private types
+
+pub use clean::*;
+
+mod private_glob {
+    // Not part of the public API, so nothing is exposed.
+    use super::inner::*;
+
+    pub fn f() {
+        let _ = make_secret();
+    }
+}
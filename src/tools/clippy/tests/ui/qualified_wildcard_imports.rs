@@ -0,0 +1,31 @@
+#![warn(clippy::qualified_wildcard_imports)]
+#![allow(clippy::wildcard_imports)]
+
+mod some {
+    pub mod long {
+        pub mod path {
+            pub struct Name;
+            pub struct Other;
+            pub fn make() {}
+        }
+    }
+    pub mod other {
+        pub fn helper() {}
+    }
+}
+
+use some::long::path::*;
+//~^ ERROR: wildcard import from a module whose items are mostly used with a qualified path
+use some::other::*;
+
+fn main() {
+    let _ = some::long::path::Name;
+    let _ = some::long::path::Other;
+    some::long::path::make();
+    let _ = Name;
+
+    // Mostly unqualified usage is fine.
+    some::other::helper();
+    helper();
+    helper();
+}
@@ -0,0 +1,118 @@
+#![warn(clippy::repeated_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+}
+
+mod a {
+    use crate::shapes::*;
+    //~^ ERROR: the same wildcard import is repeated in 3 modules
+
+    pub fn circle() -> Circle {
+        Circle
+    }
+}
+
+mod b {
+    use crate::shapes::*;
+
+    pub fn square() -> Square {
+        Square
+    }
+}
+
+mod c {
+    use crate::shapes::*;
+
+    pub fn both() -> (Circle, Square) {
+        (Circle, Square)
+    }
+}
+
+// Only imported in two modules.
+mod colors {
+    pub struct Red;
+}
+
+mod d {
+    use crate::colors::*;
+
+    pub fn red() -> Red {
+        Red
+    }
+}
+
+mod e {
+    use crate::colors::*;
+
+    pub fn red() -> Red {
+        Red
+    }
+}
+
+// Re-exports aren't duplicates.
+mod f {
+    pub use crate::colors::*;
+}
+
+// Preludes are meant to be imported everywhere.
+mod prelude {
+    pub struct Tool;
+}
+
+mod g {
+    use crate::prelude::*;
+
+    pub fn tool() -> Tool {
+        Tool
+    }
+}
+
+mod h {
+    use crate::prelude::*;
+
+    pub fn tool() -> Tool {
+        Tool
+    }
+}
+
+mod i {
+    use crate::prelude::*;
+
+    pub fn tool() -> Tool {
+        Tool
+    }
+}
+
+// Sibling modules importing their parent.
+mod parent {
+    pub struct Item;
+
+    mod j {
+        use super::*;
+
+        fn item() -> Item {
+            Item
+        }
+    }
+
+    mod k {
+        use super::*;
+
+        fn item() -> Item {
+            Item
+        }
+    }
+
+    mod l {
+        use super::*;
+
+        fn item() -> Item {
+            Item
+        }
+    }
+}
+
+fn main() {}
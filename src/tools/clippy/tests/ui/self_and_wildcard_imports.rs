@@ -0,0 +1,47 @@
+#![warn(clippy::self_and_wildcard_imports)]
+#![allow(clippy::wildcard_imports, dead_code)]
+
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+    pub fn make() -> Bar {
+        Bar
+    }
+}
+
+mod one_item {
+    use crate::foo::{self, *};
+    //~^ ERROR: wildcard import next to `self` which only brings in a single name
+
+    fn run() -> Bar {
+        foo::make()
+    }
+}
+
+mod two_items {
+    use crate::foo::{self, *};
+
+    fn run() -> (Bar, Baz) {
+        (foo::make(), Baz)
+    }
+}
+
+mod without_self {
+    use crate::foo::{make, *};
+
+    fn run() -> Bar {
+        make()
+    }
+}
+
+#[warn(clippy::wildcard_imports)]
+mod wildcard_imports_enabled {
+    use crate::foo::{self, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn run() -> Bar {
+        foo::make()
+    }
+}
+
+fn main() {}
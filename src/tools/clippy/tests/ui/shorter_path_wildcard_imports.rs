@@ -0,0 +1,49 @@
+#![warn(clippy::shorter_path_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod shapes {
+    pub use self::round::Circle;
+
+    pub mod round {
+        pub struct Circle;
+        pub struct Ellipse;
+    }
+}
+
+mod circle_user {
+    use crate::shapes::round::*;
+    //~^ ERROR: the names used from this wildcard import are also exported by a module with a shorter path
+
+    pub fn circle() -> Circle {
+        Circle
+    }
+}
+
+// `Ellipse` is only exported by `round`.
+mod ellipse_user {
+    use crate::shapes::round::*;
+
+    pub fn shapes() -> (Circle, Ellipse) {
+        (Circle, Ellipse)
+    }
+}
+
+mod map_user {
+    use std::collections::hash_map::*;
+    //~^ ERROR: the names used from this wildcard import are also exported by a module with a shorter path
+
+    pub fn map() -> HashMap<u32, u32> {
+        HashMap::new()
+    }
+}
+
+// `Entry` isn't re-exported by `std::collections`.
+mod entry_user {
+    use std::collections::hash_map::*;
+
+    pub fn entry(map: &mut HashMap<u32, u32>) -> Entry<'_, u32, u32> {
+        map.entry(0)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+#![warn(clippy::single_function_wildcard_imports)]
+#![allow(dead_code, clippy::wildcard_imports)]
+
+mod shapes {
+    pub struct Circle;
+    pub struct Square;
+    pub fn area() -> u32 {
+        0
+    }
+}
+
+mod tools {
+    pub fn hammer() {}
+    pub fn saw() {}
+}
+
+mod sizes {
+    pub struct Size(pub u32);
+}
+
+mod only_here {
+    use crate::shapes::*;
+    //~^ ERROR: wildcard import is only used inside a single function
+
+    fn draw() {
+        let _ = Circle;
+        let _ = Square;
+        let _ = area();
+        let _ = || Circle;
+    }
+}
+
+mod two_functions {
+    use crate::tools::*;
+
+    fn build() {
+        hammer();
+    }
+
+    fn cut() {
+        saw();
+    }
+}
+
+mod in_signature {
+    // Moving the import into the body wouldn't cover the signature.
+    use crate::sizes::*;
+
+    fn grow(size: Size) -> u32 {
+        let Size(s) = size;
+        s + 1
+    }
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+//@aux-build:trait_exports.rs
+#![warn(clippy::trait_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+extern crate trait_exports;
+
+mod local {
+    pub trait LocalTrait {}
+}
+
+mod shapes_user {
+    use trait_exports::shapes::*;
+    //~^ ERROR: wildcard import brings traits of another crate into scope
+
+    pub fn area() -> f64 {
+        Square(2.0).area()
+    }
+}
+
+mod sized_user {
+    use trait_exports::sized::*;
+    //~^ ERROR: wildcard import brings traits of another crate into scope
+}
+
+// No traits, or only traits of the current crate.
+mod unlinted {
+    use crate::local::*;
+    use trait_exports::plain::*;
+}
+
+// Preludes are meant to be imported with a glob.
+mod prelude_user {
+    use trait_exports::prelude::*;
+
+    pub fn area() -> f64 {
+        trait_exports::shapes::Square(2.0).area()
+    }
+}
+
+fn main() {
+    shapes_user::area();
+}
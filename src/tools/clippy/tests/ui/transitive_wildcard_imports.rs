@@ -0,0 +1,36 @@
+#![warn(clippy::transitive_wildcard_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod foo {
+    pub struct Leaked;
+}
+
+mod inner {
+    pub use crate::foo::*;
+    pub struct Own;
+}
+
+mod private_glob {
+    use crate::foo::*;
+    pub struct OtherOwn;
+}
+
+mod crate_glob {
+    pub(crate) use crate::foo::*;
+    pub struct ThirdOwn;
+}
+
+// `Leaked` becomes part of the public API through both wildcards.
+pub use inner::*;
+//~^ ERROR: this wildcard re-export also re-exports the wildcard imports of `inner`
+
+// Allowed: the inner wildcard imports aren't re-exported as public.
+pub use crate_glob::*;
+pub use private_glob::*;
+
+mod not_exported {
+    // Allowed: this re-export isn't part of the public API.
+    pub use crate::inner::*;
+}
+
+fn main() {}
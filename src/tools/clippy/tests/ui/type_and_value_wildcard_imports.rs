@@ -0,0 +1,35 @@
+#![warn(clippy::type_and_value_wildcard_imports)]
+#![allow(dead_code, unused_imports, clippy::wildcard_imports)]
+
+mod unit {
+    pub struct Unit;
+    pub struct Named {
+        pub x: u32,
+    }
+}
+
+mod separate {
+    pub struct Thing {
+        pub x: u32,
+    }
+    #[allow(non_snake_case)]
+    pub fn Thing() {}
+    pub struct Tuple(pub u32);
+}
+
+mod distinct {
+    pub struct Named {}
+    pub fn named() {}
+    // The constructor is private, so only the type is imported.
+    pub struct Opaque(u32);
+    // Not visible to the glob at all.
+    struct Hidden;
+}
+
+use unit::*;
+//~^ ERROR: wildcard import brings in a type and a value with the same name
+use separate::*;
+//~^ ERROR: wildcard import brings in a type and a value with the same name
+use distinct::*;
+
+fn main() {}
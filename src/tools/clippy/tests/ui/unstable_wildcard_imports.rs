@@ -0,0 +1,30 @@
+//@aux-build:unstable_exports.rs
+#![feature(shiny_gloss)]
+#![warn(clippy::unstable_wildcard_imports)]
+#![allow(clippy::wildcard_imports)]
+
+extern crate unstable_exports;
+
+mod shiny_user {
+    use unstable_exports::shiny::*;
+    //~^ ERROR: wildcard import brings unstable items into scope
+
+    pub fn shine() {
+        polish();
+        gloss();
+    }
+}
+
+// The feature of `matte` isn't enabled, so it can't be used through the glob.
+mod dull_user {
+    use unstable_exports::dull::*;
+
+    pub fn paint() {
+        plain();
+    }
+}
+
+fn main() {
+    shiny_user::shine();
+    dull_user::paint();
+}
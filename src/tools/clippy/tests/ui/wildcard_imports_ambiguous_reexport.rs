@@ -0,0 +1,32 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(non_snake_case)]
+
+mod foo {
+    mod a {
+        pub struct Bar {}
+    }
+    mod b {
+        pub fn Bar() {}
+    }
+    mod c {
+        pub struct Baz;
+    }
+    pub use a::*;
+    pub use b::*;
+    pub use c::*;
+}
+
+mod user {
+    use crate::foo::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn use_all() {
+        let _ = Bar {};
+        Bar();
+        let _ = Baz;
+    }
+}
+
+fn main() {
+    user::use_all();
+}
@@ -0,0 +1,22 @@
+#![warn(clippy::wildcard_imports)]
+
+mod names {
+    pub const FIRST: u32 = 1;
+    pub const SECOND: u32 = 2;
+}
+
+// The pattern derive macros use to keep their helper imports out of the surrounding module.
+const _: () = {
+    use crate::names::*;
+    assert!(FIRST < SECOND);
+};
+
+const SUM: u32 = {
+    use crate::names::*;
+    //~^ ERROR: usage of wildcard import
+    FIRST + SECOND
+};
+
+fn main() {
+    let _ = SUM;
+}
@@ -0,0 +1,59 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(unused)]
+
+mod foo {
+    pub struct Alpha;
+    pub struct Bar;
+    pub struct Baz;
+    pub struct Zed;
+}
+
+mod glob_last {
+    use crate::foo::{Zed, Alpha, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = (Zed, Alpha, Bar, Baz);
+    }
+}
+
+mod glob_first {
+    use crate::foo::{*, Zed, Alpha};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = (Zed, Alpha, Bar, Baz);
+    }
+}
+
+mod glob_last_trailing_comma {
+    use crate::foo::{Zed, Alpha, *,};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = (Zed, Alpha, Bar, Baz);
+    }
+}
+
+mod glob_middle_trailing_comma {
+    use crate::foo::{Zed, *, Alpha,};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = (Zed, Alpha, Bar, Baz);
+    }
+}
+
+mod glob_last_multiline {
+    use crate::foo::{
+        Zed,
+        Alpha,
+        *, //~ ERROR: usage of wildcard import
+    };
+
+    fn f() {
+        let _ = (Zed, Alpha, Bar, Baz);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(dead_code)]
+
+mod foo {
+    pub fn bar() {}
+    pub fn baz() {}
+    pub fn qux() {}
+}
+
+use foo::bar;
+use foo::*;
+//~^ ERROR: usage of wildcard import
+
+mod braced {
+    use crate::foo::{qux, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn uses() {
+        qux();
+        baz();
+    }
+}
+
+mod types {
+    pub struct Bar;
+    pub struct Baz;
+}
+
+// The explicit import names the same module through a different path.
+mod spelled_differently {
+    use super::types::*;
+    //~^ ERROR: usage of wildcard import
+    use crate::types::Bar;
+
+    fn uses() {
+        let _ = (Bar, Baz);
+    }
+}
+
+fn main() {
+    bar();
+    baz();
+}
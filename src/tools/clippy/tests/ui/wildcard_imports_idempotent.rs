@@ -0,0 +1,75 @@
+// Every import below is what `wildcard_imports` suggests for a glob import, so
+// running the lint over already-fixed code must not emit anything.
+
+#![warn(clippy::wildcard_imports)]
+#![allow(dead_code)]
+
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+    pub struct Qux;
+    pub fn quux() {}
+}
+
+mod macros {
+    #[macro_export]
+    macro_rules! make_bar {
+        () => {
+            $crate::foo::Bar
+        };
+    }
+}
+
+mod reexports {
+    pub use crate::make_bar;
+    pub struct Thing;
+}
+
+mod single {
+    use crate::foo::Bar;
+
+    fn f() {
+        let _ = Bar;
+    }
+}
+
+mod multiple {
+    use crate::foo::{Baz, quux};
+
+    fn f() {
+        let _ = Baz;
+        quux();
+    }
+}
+
+mod renamed {
+    use crate::foo::{Bar as MyBar, Qux};
+
+    fn f() {
+        let _ = MyBar;
+        let _ = Qux;
+    }
+}
+
+mod reexported_macro {
+    use crate::reexports::{Thing, make_bar};
+
+    fn f() {
+        let _ = make_bar!();
+        let _ = Thing;
+    }
+}
+
+mod outer {
+    pub struct Outer;
+
+    mod inner {
+        use super::Outer;
+
+        fn f() {
+            let _ = Outer;
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![warn(clippy::wildcard_imports)]
+
+// The prelude is already imported implicitly, so the glob only hides which of its names are used.
+use std::prelude::v1::*;
+//~^ ERROR: usage of wildcard import
+
+mod inner {
+    #![no_implicit_prelude]
+
+    // Without the implicit prelude, the glob is how the module gets it.
+    use ::std::prelude::v1::*;
+
+    pub fn numbers() -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+fn main() {
+    let numbers: Vec<u8> = inner::numbers();
+    drop(numbers);
+}
@@ -0,0 +1,41 @@
+//@aux-build:lazy_static.rs
+//@aux-build:once_cell.rs
+#![warn(clippy::wildcard_imports)]
+
+#[macro_use]
+extern crate lazy_static;
+extern crate once_cell;
+
+use once_cell::sync::Lazy;
+
+mod names {
+    pub fn first() -> String {
+        "first".to_owned()
+    }
+
+    pub fn second() -> String {
+        "second".to_owned()
+    }
+}
+
+lazy_static! {
+    static ref LAZY_STATIC_NAMES: Vec<String> = {
+        use crate::names::*;
+        vec![first(), second()]
+    };
+}
+
+static ONCE_CELL_NAMES: Lazy<Vec<String>> = Lazy::new(|| {
+    use crate::names::*;
+    vec![first(), second()]
+});
+
+fn names() -> Vec<String> {
+    use crate::names::*;
+    //~^ ERROR: usage of wildcard import
+    vec![first(), second()]
+}
+
+fn main() {
+    let _ = (LAZY_STATIC_NAMES.len(), ONCE_CELL_NAMES.len(), names());
+}
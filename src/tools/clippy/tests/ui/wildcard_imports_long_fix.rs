@@ -0,0 +1,26 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(dead_code)]
+
+mod configuration {
+    pub struct DatabaseConnectionSettings;
+    pub struct NetworkConnectionSettings;
+    pub struct LoggingOutputSettings;
+    pub struct FileSystemSettings;
+}
+
+mod short {
+    pub struct A;
+}
+
+use configuration::*;
+//~^ ERROR: usage of wildcard import
+use short::*;
+//~^ ERROR: usage of wildcard import
+
+fn main() {
+    let _ = DatabaseConnectionSettings;
+    let _ = NetworkConnectionSettings;
+    let _ = LoggingOutputSettings;
+    let _ = FileSystemSettings;
+    let _ = A;
+}
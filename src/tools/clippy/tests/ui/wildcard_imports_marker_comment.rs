@@ -0,0 +1,54 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(unused_imports)]
+
+mod generated {
+    pub fn first() {}
+    pub fn second() {}
+}
+
+mod marked {
+    // clippy:wildcard-ok
+    use crate::generated::*;
+
+    pub fn call() {
+        first();
+    }
+}
+
+mod marked_with_attribute {
+    // clippy:wildcard-ok
+    #[rustfmt::skip]
+    use crate::generated::*;
+
+    pub fn call() {
+        first();
+    }
+}
+
+mod other_comment {
+    // clippy: wildcard is fine here
+    use crate::generated::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn call() {
+        second();
+    }
+}
+
+mod separated {
+    // clippy:wildcard-ok
+
+    use crate::generated::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn call() {
+        second();
+    }
+}
+
+fn main() {
+    marked::call();
+    marked_with_attribute::call();
+    other_comment::call();
+    separated::call();
+}
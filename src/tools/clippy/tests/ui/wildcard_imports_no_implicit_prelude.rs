@@ -0,0 +1,19 @@
+#![warn(clippy::wildcard_imports)]
+#![no_implicit_prelude]
+
+// Without the implicit prelude, the glob is how the crate gets it.
+use ::std::prelude::v1::*;
+
+mod inner {
+    // `#[no_implicit_prelude]` also applies to the modules nested in the crate.
+    use ::std::prelude::v1::*;
+
+    pub fn numbers() -> Vec<u8> {
+        Vec::new()
+    }
+}
+
+fn main() {
+    let numbers: Vec<u8> = inner::numbers();
+    drop(numbers);
+}
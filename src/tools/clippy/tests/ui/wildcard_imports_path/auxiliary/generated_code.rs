@@ -0,0 +1,5 @@
+use crate::items::*;
+
+pub fn call() {
+    item();
+}
@@ -0,0 +1,28 @@
+//@no-rustfix
+#![warn(clippy::wildcard_imports)]
+
+mod items {
+    pub fn item() {}
+}
+
+// Allowed: the file names contain one of the default `wildcard-imports-generated-file-patterns` as a word.
+#[path = "auxiliary/generated_code.rs"]
+mod generated_code;
+#[path = "auxiliary/bindings.rs"]
+mod bindings;
+#[path = "auxiliary/ffi.rs"]
+mod ffi;
+#[path = "auxiliary/libfoo-sys.rs"]
+mod libfoo_sys;
+
+// Not allowed: `sys` is only a part of the word `system`.
+#[path = "auxiliary/system.rs"]
+mod system;
+
+fn main() {
+    generated_code::call();
+    bindings::call();
+    ffi::call();
+    libfoo_sys::call();
+    system::call();
+}
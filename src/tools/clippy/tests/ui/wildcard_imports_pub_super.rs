@@ -0,0 +1,21 @@
+#![warn(clippy::wildcard_imports)]
+
+// A `pub use super::*` in a public module deliberately re-exports its parent as part of the API.
+
+pub struct Config;
+
+pub fn build() -> Config {
+    Config
+}
+
+pub mod api {
+    pub use super::*;
+
+    pub fn default_config() -> Config {
+        build()
+    }
+}
+
+fn main() {
+    let _: api::Config = api::default_config();
+}
@@ -0,0 +1,29 @@
+#![warn(clippy::wildcard_imports)]
+
+mod macros {
+    #[macro_export]
+    macro_rules! make_thing {
+        () => {
+            $crate::things::Thing
+        };
+    }
+}
+
+mod things {
+    pub use crate::make_thing;
+    pub struct Thing;
+}
+
+mod user {
+    use crate::things::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn make() {
+        let _ = make_thing!();
+        let _ = Thing;
+    }
+}
+
+fn main() {
+    user::make();
+}
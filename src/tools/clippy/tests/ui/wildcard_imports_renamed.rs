@@ -0,0 +1,42 @@
+#![warn(clippy::wildcard_imports)]
+#![allow(unused)]
+
+mod foo {
+    pub struct Bar;
+    pub struct Baz;
+    pub struct Qux;
+}
+
+mod renamed {
+    use crate::foo::{Bar as MyBar, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = MyBar;
+        let _ = Baz;
+    }
+}
+
+mod renamed_and_original {
+    use crate::foo::{Bar as MyBar, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = MyBar;
+        let _ = Bar;
+        let _ = Qux;
+    }
+}
+
+mod multiple_renames {
+    use crate::foo::{Bar as MyBar, Baz as MyBaz, *};
+    //~^ ERROR: usage of wildcard import
+
+    fn f() {
+        let _ = MyBar;
+        let _ = MyBaz;
+        let _ = Qux;
+    }
+}
+
+fn main() {}
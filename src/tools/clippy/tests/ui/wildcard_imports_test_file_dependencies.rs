@@ -0,0 +1,53 @@
+//@aux-build:wildcard_imports_helper.rs
+#![warn(clippy::wildcard_imports)]
+
+// This file is in the `tests` directory of Clippy and isn't built with the test harness, so
+// imports from `itertools` (a dev-dependency) and `wildcard_imports_helper` (standing in for a
+// normal dependency) are allowed, but `std` comes from the sysroot.
+
+mod helpers {
+    pub fn setup() {}
+    pub fn teardown() {}
+}
+
+mod dev_dependency {
+    use itertools::*;
+
+    pub fn pairs() -> Vec<(u8, u8)> {
+        repeat_n(1, 2).zip(0..2).collect()
+    }
+}
+
+mod dependency {
+    use wildcard_imports_helper::*;
+
+    pub fn run() {
+        extern_foo();
+    }
+}
+
+mod sysroot {
+    use std::collections::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn set() -> HashSet<u8> {
+        HashSet::new()
+    }
+}
+
+mod local {
+    use crate::helpers::*;
+    //~^ ERROR: usage of wildcard import
+
+    pub fn run() {
+        setup();
+    }
+}
+
+fn main() {
+    dev_dependency::pairs();
+    dependency::run();
+    sysroot::set();
+    local::run();
+    helpers::teardown();
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::wildcard_imports)]
+
+mod shapes {
+    pub fn area() -> u32 {
+        1
+    }
+    pub fn perimeter() -> u32 {
+        4
+    }
+}
+
+pub trait Shape {
+    // `use` items in provided methods are linted like those of any other function.
+    fn area(&self) -> u32 {
+        use crate::shapes::*;
+        //~^ ERROR: usage of wildcard import
+        area()
+    }
+}
+
+fn main() {
+    shapes::perimeter();
+}
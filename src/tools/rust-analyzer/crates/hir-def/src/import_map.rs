@@ -119,6 +119,15 @@ pub(crate) fn import_map_query(db: &dyn DefDatabase, krate: CrateId) -> Arc<Self
         Arc::new(ImportMap { item_to_info_map: map, fst: builder.into_map(), importables })
     }
 
+    /// Number of entries in the index, one per name an item can be imported by.
+    pub fn len(&self) -> usize {
+        self.importables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn import_info_for(&self, item: ItemInNs) -> Option<&[ImportInfo]> {
         self.item_to_info_map.get(&item).map(|(info, _)| &**info)
     }
@@ -111,7 +111,7 @@
 // Generally, a refactoring which *removes* a name from this list is a good
 // idea!
 pub use {
-    cfg::{CfgAtom, CfgExpr, CfgOptions},
+    cfg::{CfgAtom, CfgExpr, CfgOptions, InactiveReason},
     hir_def::{
         attr::{builtin::AttributeTemplate, AttrSourceMap, Attrs, AttrsWithOwner},
         data::adt::StructKind,
@@ -544,6 +544,30 @@ pub fn scope(
             .collect()
     }
 
+    /// Returns the items of this module that are left out by their `cfg` attribute, along with
+    /// why the `cfg` is not satisfied.
+    pub fn cfg_disabled_items(
+        self,
+        db: &dyn HirDatabase,
+    ) -> Vec<(InFile<ast::Item>, Option<InactiveReason>)> {
+        let def_map = self.id.def_map(db.upcast());
+        def_map
+            .diagnostics()
+            .iter()
+            .filter(|diag| diag.in_module == self.id.local_id)
+            .filter_map(|diag| match &diag.kind {
+                DefDiagnosticKind::UnconfiguredCode { ast, cfg, opts } => {
+                    let root = db.parse_or_expand(ast.file_id);
+                    let item =
+                        <ast::Item as AstNode>::cast(ast.to_ptr(db.upcast()).to_node(&root))?;
+                    let reason = cfg::DnfExpr::new(cfg.clone()).why_inactive(opts);
+                    Some((ast.with_value(item), reason))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Fills `acc` with the module's diagnostics.
     pub fn diagnostics(
         self,
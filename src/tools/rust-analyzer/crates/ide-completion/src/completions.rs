@@ -25,8 +25,11 @@
 use std::iter;
 
 use hir::{known, HasAttrs, ImportPathConfig, ScopeDef, Variant};
-use ide_db::{imports::import_assets::LocatedImport, RootDatabase, SymbolKind};
-use syntax::{ast, SmolStr};
+use ide_db::{imports::import_assets::LocatedImport, FxHashSet, RootDatabase, SymbolKind};
+use syntax::{
+    ast::{self, HasName},
+    SmolStr,
+};
 
 use crate::{
     context::{
@@ -188,6 +191,11 @@ pub(crate) fn add_path_resolution(
         if !ctx.check_stability(resolution.attrs(ctx.db).as_deref()) {
             return;
         }
+        if resolution == hir::ScopeDef::Unknown
+            && !ctx.config.enable_completions_for_unresolved_imports
+        {
+            return;
+        }
         let is_private_editable = match ctx.def_is_visible(&resolution) {
             Visible::Yes => false,
             Visible::Editable => true,
@@ -206,6 +214,47 @@ pub(crate) fn add_path_resolution(
         );
     }
 
+    /// Adds the items of `module` that are left out by their `cfg` attribute, unless an active item
+    /// has the same name. In type position, only the items that can start a type path are added.
+    pub(crate) fn add_cfg_disabled_items(
+        &mut self,
+        ctx: &CompletionContext<'_>,
+        module: hir::Module,
+        in_type_position: bool,
+    ) {
+        if !ctx.config.enable_completions_for_cfg_variants {
+            return;
+        }
+        let mut seen: FxHashSet<SmolStr> =
+            module.scope(ctx.db, None).into_iter().map(|(name, _)| name.to_smol_str()).collect();
+        for (item, reason) in module.cfg_disabled_items(ctx.db) {
+            let (kind, name) = match item.value {
+                ast::Item::Const(it) if !in_type_position => (SymbolKind::Const, it.name()),
+                ast::Item::Enum(it) => (SymbolKind::Enum, it.name()),
+                ast::Item::Fn(it) if !in_type_position => (SymbolKind::Function, it.name()),
+                ast::Item::Module(it) => (SymbolKind::Module, it.name()),
+                ast::Item::Static(it) if !in_type_position => (SymbolKind::Static, it.name()),
+                ast::Item::Struct(it) => (SymbolKind::Struct, it.name()),
+                ast::Item::Trait(it) => (SymbolKind::Trait, it.name()),
+                ast::Item::TraitAlias(it) => (SymbolKind::TraitAlias, it.name()),
+                ast::Item::TypeAlias(it) => (SymbolKind::TypeAlias, it.name()),
+                ast::Item::Union(it) => (SymbolKind::Union, it.name()),
+                _ => continue,
+            };
+            let Some(name) = name else { continue };
+            let name = SmolStr::from(name.text().as_str());
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            let mut item = CompletionItem::new(kind, ctx.source_range(), name);
+            item.detail(match reason {
+                Some(reason) => format!("inactive: {reason}"),
+                None => "inactive".to_owned(),
+            });
+            item.add_to(self, ctx.db);
+        }
+    }
+
     pub(crate) fn add_pattern_resolution(
         &mut self,
         ctx: &CompletionContext<'_>,
@@ -745,13 +794,17 @@ pub(super) fn complete_name_ref(
                     }
                 }
                 PathKind::Pat { .. } => {
-                    pattern::complete_pattern_path(acc, ctx, path_ctx);
+                    if ctx.config.enable_completions_for_pattern_bindings {
+                        pattern::complete_pattern_path(acc, ctx, path_ctx);
+                    }
                 }
                 PathKind::Vis { has_in_token } => {
                     vis::complete_vis_path(acc, ctx, path_ctx, has_in_token);
                 }
                 PathKind::Use => {
-                    use_::complete_use_path(acc, ctx, path_ctx, nameref);
+                    if ctx.config.enable_completions_for_use_tree {
+                        use_::complete_use_path(acc, ctx, path_ctx, nameref);
+                    }
                 }
             }
         }
@@ -776,6 +829,9 @@ fn complete_patterns(
     ctx: &CompletionContext<'_>,
     pattern_ctx: &PatternContext,
 ) {
+    if !ctx.config.enable_completions_for_pattern_bindings {
+        return;
+    }
     flyimport::import_on_the_fly_pat(acc, ctx, pattern_ctx);
     fn_param::complete_fn_param(acc, ctx, pattern_ctx);
     pattern::complete_pattern(acc, ctx, pattern_ctx);
@@ -97,6 +97,7 @@ pub(crate) fn complete_expr_path(
                             );
                         }
                     }
+                    acc.add_cfg_disabled_items(ctx, *module, false);
                 }
                 hir::PathResolution::Def(
                     def @ (hir::ModuleDef::Adt(_)
@@ -242,6 +243,7 @@ pub(crate) fn complete_expr_path(
                 }
                 _ => (),
             });
+            acc.add_cfg_disabled_items(ctx, ctx.module.nearest_non_block_module(ctx.db), false);
 
             match is_func_update {
                 Some(record_expr) => {
@@ -8,6 +8,9 @@
 use super::Completions;
 
 pub(crate) fn complete_extern_crate(acc: &mut Completions, ctx: &CompletionContext<'_>) {
+    if !ctx.config.enable_completions_for_extern_crate {
+        return;
+    }
     let imported_extern_crates: Vec<Name> = ctx.scope.extern_crate_decls().collect();
 
     for (name, module) in ctx.scope.extern_crates() {
@@ -28,7 +31,10 @@ pub(crate) fn complete_extern_crate(acc: &mut Completions, ctx: &CompletionConte
 
 #[cfg(test)]
 mod test {
-    use crate::tests::completion_list_no_kw;
+    use crate::{
+        tests::{completion_list_no_kw, get_all_items, TEST_CONFIG},
+        CompletionConfig,
+    };
 
     #[test]
     fn can_complete_extern_crate() {
@@ -68,4 +74,20 @@ mod other_mod {}
 
         assert_eq!("md other_crate_a\n".to_owned(), completion_list);
     }
+
+    #[test]
+    fn extern_crate_completion_disabled() {
+        let config = CompletionConfig { enable_completions_for_extern_crate: false, ..TEST_CONFIG };
+        let items = get_all_items(
+            config,
+            r#"
+//- /lib.rs crate:other_crate_a
+// nothing here
+//- /lib.rs crate:lib deps:other_crate_a extern-prelude:other_crate_a
+extern crate oth$0
+"#,
+            None,
+        );
+        assert!(items.is_empty());
+    }
 }
@@ -416,7 +416,7 @@ fn import_assets_for_path(
     if fuzzy_name_length == 0 {
         // nothing matches the empty string exactly, but we still compute assoc items in this case
         assets_for_path.path_fuzzy_name_to_exact();
-    } else if fuzzy_name_length < 3 {
+    } else if fuzzy_name_length < ctx.config.min_word_length_for_fuzzy {
         cov_mark::hit!(flyimport_prefix_on_short_path);
         assets_for_path.path_fuzzy_name_to_prefix();
     }
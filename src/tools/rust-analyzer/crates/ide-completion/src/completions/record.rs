@@ -231,6 +231,126 @@ fn new(u: usize) -> Self {
         )
     }
 
+    #[test]
+    fn literal_struct_completion_without_auto_brace() {
+        let conf = CompletionConfig { enable_auto_brace: false, ..TEST_CONFIG };
+        check_edit_with_config(
+            conf.clone(),
+            "FooDesc",
+            r#"
+struct FooDesc { pub bar: bool }
+
+fn create_foo(foo_desc: &FooDesc) -> () { () }
+
+fn baz() {
+    let foo = create_foo(&$0);
+}
+"#,
+            r#"
+struct FooDesc { pub bar: bool }
+
+fn create_foo(foo_desc: &FooDesc) -> () { () }
+
+fn baz() {
+    let foo = create_foo(&FooDesc);
+}
+"#,
+        );
+
+        check_edit_with_config(
+            conf.clone(),
+            "Union",
+            r#"
+union Union { foo: u32, bar: f32 }
+
+fn baz() {
+    let u = Un$0;
+}
+"#,
+            r#"
+union Union { foo: u32, bar: f32 }
+
+fn baz() {
+    let u = Union;
+}
+"#,
+        );
+
+        check_edit_with_config(
+            conf.clone(),
+            "submod::Struct",
+            r#"
+mod submod {
+    pub struct Struct {
+        pub a: u64,
+    }
+}
+
+fn f() -> submod::Struct {
+    Stru$0
+}
+"#,
+            r#"
+mod submod {
+    pub struct Struct {
+        pub a: u64,
+    }
+}
+
+fn f() -> submod::Struct {
+    submod::Struct$0
+}
+"#,
+        );
+
+        check_edit_with_config(
+            conf.clone(),
+            "Variant",
+            r#"
+enum Enum {
+    Variant { u: usize },
+}
+
+impl Enum {
+    fn new(u: usize) -> Self {
+        Self::Va$0
+    }
+}
+"#,
+            r#"
+enum Enum {
+    Variant { u: usize },
+}
+
+impl Enum {
+    fn new(u: usize) -> Self {
+        Self::Variant$0
+    }
+}
+"#,
+        );
+
+        // Parentheses of tuple structs are still inserted.
+        check_edit_with_config(
+            conf,
+            "Foo()",
+            r#"
+struct Foo(u64);
+
+fn baz() -> Foo {
+    Fo$0
+}
+"#,
+            r#"
+struct Foo(u64);
+
+fn baz() -> Foo {
+    Foo(${1:()})$0
+}
+"#,
+        );
+    }
+
     #[test]
     fn literal_struct_impl_self_completion() {
         check_edit(
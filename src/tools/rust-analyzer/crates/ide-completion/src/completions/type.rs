@@ -92,6 +92,7 @@ pub(crate) fn complete_type_path(
                             acc.add_path_resolution(ctx, path_ctx, name, def, vec![]);
                         }
                     }
+                    acc.add_cfg_disabled_items(ctx, *module, true);
                 }
                 hir::PathResolution::Def(
                     def @ (hir::ModuleDef::Adt(_)
@@ -213,6 +214,7 @@ pub(crate) fn complete_type_path(
                     acc.add_path_resolution(ctx, path_ctx, name, def, doc_aliases);
                 }
             });
+            acc.add_cfg_disabled_items(ctx, ctx.module.nearest_non_block_module(ctx.db), true);
         }
     }
 }
@@ -65,7 +65,10 @@ pub(crate) fn complete_use_path(
                                 cov_mark::hit!(dont_complete_current_use);
                                 continue;
                             }
-                            ScopeDef::ModuleDef(_) | ScopeDef::Unknown => true,
+                            ScopeDef::Unknown => {
+                                ctx.config.enable_completions_for_unresolved_imports
+                            }
+                            ScopeDef::ModuleDef(_) => true,
                             _ => false,
                         };
 
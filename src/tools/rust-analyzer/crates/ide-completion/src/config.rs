@@ -14,16 +14,35 @@ pub struct CompletionConfig {
     pub enable_imports_on_the_fly: bool,
     pub enable_self_on_the_fly: bool,
     pub enable_private_editable: bool,
+    pub enable_completions_for_pattern_bindings: bool,
+    pub enable_completions_for_use_tree: bool,
+    pub enable_completions_for_unresolved_imports: bool,
+    pub enable_completions_for_extern_crate: bool,
+    pub enable_completions_for_use_aliases: bool,
+    /// Whether to also complete the items that are left out by their `cfg` attribute, like the
+    /// ones behind a disabled feature.
+    pub enable_completions_for_cfg_variants: bool,
+    pub include_deprecated: bool,
     pub enable_term_search: bool,
     pub term_search_fuel: u64,
+    pub max_type_inference_depth: u32,
+    /// Typed names shorter than this are only matched as a prefix of the items to import, instead
+    /// of fuzzily.
+    pub min_word_length_for_fuzzy: usize,
     pub full_function_signatures: bool,
     pub callable: Option<CallableSnippets>,
+    /// Whether to insert the braces of record struct, variant and union literals, with
+    /// placeholders for their fields.
+    pub enable_auto_brace: bool,
     pub snippet_cap: Option<SnippetCap>,
     pub insert_use: InsertUseConfig,
     pub prefer_no_std: bool,
     pub prefer_prelude: bool,
     pub snippets: Vec<Snippet>,
     pub limit: Option<usize>,
+    /// Whether the most relevant item of a completion list is marked as preselected for the
+    /// client. At most one item is marked.
+    pub preselect_first_item: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -591,6 +591,9 @@ pub(crate) fn process_all_names(&self, f: &mut dyn FnMut(Name, ScopeDef, Vec<Smo
             if self.is_scope_def_hidden(def) {
                 return;
             }
+            if !self.config.enable_completions_for_use_aliases && self.is_use_alias(&name, def) {
+                return;
+            }
             let doc_aliases = self.doc_aliases_in_scope(def);
             f(name, def, doc_aliases);
         });
@@ -610,6 +613,15 @@ fn is_scope_def_hidden(&self, scope_def: ScopeDef) -> bool {
         false
     }
 
+    /// Whether `name` refers to `scope_def` under a name other than its own, as with
+    /// `use foo::Bar as Baz;`.
+    fn is_use_alias(&self, name: &Name, scope_def: ScopeDef) -> bool {
+        match scope_def {
+            ScopeDef::ModuleDef(def) => def.name(self.db).is_some_and(|it| it != *name),
+            _ => false,
+        }
+    }
+
     fn is_visible_impl(
         &self,
         vis: &hir::Visibility,
@@ -711,6 +723,10 @@ pub(crate) fn new(
             offset,
             &original_token,
         )?;
+        // Matching every completion against a deeply nested expected type gets expensive, so past
+        // the configured depth we complete as if the type wasn't known.
+        let expected_type =
+            expected_type.filter(|ty| type_depth_within(db, ty, config.max_type_inference_depth));
 
         // adjust for macro input, this still fails if there is no token written yet
         let scope = sema.scope_at_offset(&token.parent()?, offset)?;
@@ -753,6 +769,16 @@ pub(crate) fn new(
     }
 }
 
+/// Checks that `ty` nests no more than `max_depth` levels of generic arguments and array elements.
+fn type_depth_within(db: &RootDatabase, ty: &hir::Type, max_depth: u32) -> bool {
+    let Some(max_depth) = max_depth.checked_sub(1) else {
+        return false;
+    };
+    ty.type_arguments()
+        .chain(ty.as_array(db).map(|(elem, _)| elem))
+        .all(|inner| type_depth_within(db, &inner, max_depth))
+}
+
 const OP_TRAIT_LANG_NAMES: &[&str] = &[
     "add_assign",
     "add",
@@ -43,6 +43,8 @@ pub(super) fn expand_and_analyze(
     offset: TextSize,
     original_token: &SyntaxToken,
 ) -> Option<AnalysisResult> {
+    let _p =
+        tracing::span!(tracing::Level::INFO, "CompletionContext::expand_and_analyze").entered();
     // as we insert after the offset, right biased will *always* pick the identifier no matter
     // if there is an ident already typed or not
     let fake_ident_token = speculative_file.token_at_offset(offset).right_biased()?;
@@ -4,11 +4,19 @@
 use crate::{
     context::CompletionContext,
     tests::{position, TEST_CONFIG},
+    CompletionConfig,
 };
 
 fn check_expected_type_and_name(ra_fixture: &str, expect: Expect) {
+    check_expected_type_and_name_with_config(TEST_CONFIG, ra_fixture, expect);
+}
+
+fn check_expected_type_and_name_with_config(
+    config: CompletionConfig,
+    ra_fixture: &str,
+    expect: Expect,
+) {
     let (db, pos) = position(ra_fixture);
-    let config = TEST_CONFIG;
     let (completion_context, _analysis) = CompletionContext::new(&db, pos, &config).unwrap();
 
     let ty = completion_context
@@ -423,3 +431,22 @@ fn f(thing: u32) -> &u32 {
         expect!["ty: u32, name: ?"],
     );
 }
+
+#[test]
+fn expected_type_max_inference_depth() {
+    let fixture = r#"
+struct W<T>(T);
+fn foo() {
+    let x: W<W<W<W<W<W<W<W<W<W<u32>>>>>>>>>> = $0;
+}
+"#;
+    check_expected_type_and_name(
+        fixture,
+        expect!["ty: W<W<W<W<W<W<W<W<W<W<u32>>>>>>>>>>, name: x"],
+    );
+    check_expected_type_and_name_with_config(
+        CompletionConfig { max_type_inference_depth: 5, ..TEST_CONFIG },
+        fixture,
+        expect!["ty: ?, name: x"],
+    );
+}
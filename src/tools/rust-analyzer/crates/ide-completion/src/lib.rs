@@ -227,7 +227,11 @@ pub fn completions(
         }
     }
 
-    Some(completions.into())
+    let mut completions: Vec<CompletionItem> = completions.into();
+    if !config.include_deprecated {
+        completions.retain(|it| !it.deprecated);
+    }
+    Some(completions)
 }
 
 /// Resolves additional completion data at the position given.
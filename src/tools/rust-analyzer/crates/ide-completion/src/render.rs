@@ -643,8 +643,9 @@ mod tests {
 
     use crate::{
         item::CompletionRelevanceTypeMatch,
-        tests::{check_edit, do_completion, get_all_items, TEST_CONFIG},
-        CompletionItem, CompletionItemKind, CompletionRelevance, CompletionRelevancePostfixMatch,
+        tests::{check_edit, do_completion, do_completion_with_config, get_all_items, TEST_CONFIG},
+        CompletionConfig, CompletionItem, CompletionItemKind, CompletionRelevance,
+        CompletionRelevancePostfixMatch,
     };
 
     #[track_caller]
@@ -1366,6 +1367,29 @@ fn foo() { A { the$0 } }
         );
     }
 
+    #[test]
+    fn omits_deprecated_items_when_disabled() {
+        let config = CompletionConfig { include_deprecated: false, ..TEST_CONFIG };
+        let actual = do_completion_with_config(
+            config,
+            r#"
+#[deprecated]
+fn something_deprecated() {}
+fn something_else() {}
+
+fn main() { som$0 }
+"#,
+            CompletionItemKind::SymbolKind(SymbolKind::Function),
+        );
+        expect![[r#"
+            [
+                "main()",
+                "something_else()",
+            ]
+        "#]]
+        .assert_debug_eq(&actual.iter().map(|it| &it.label).collect::<Vec<_>>());
+    }
+
     #[test]
     fn renders_docs() {
         check_kinds(
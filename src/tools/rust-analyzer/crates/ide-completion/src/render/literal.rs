@@ -63,6 +63,8 @@ fn render(
             | PathCompletionCtx { kind: PathKind::Use | PathKind::Type { .. }, .. }
     );
 
+    let should_add_braces = should_add_parens && completion.config.enable_auto_brace;
+
     let fields = thing.fields(completion)?;
     let (qualified_name, short_qualified_name, qualified) = match path {
         Some(path) => {
@@ -74,6 +76,15 @@ fn render(
         }
         None => (name.clone().into(), name.into(), false),
     };
+    // Without its braces, the literal of a record struct is just its path, which is already
+    // completed on its own unless it needs to be qualified. Enum variants aren't, so they are kept.
+    if matches!((&thing, kind), (Variant::Struct(_), StructKind::Record))
+        && should_add_parens
+        && !should_add_braces
+        && !qualified
+    {
+        return None;
+    }
     let (qualified_name, escaped_qualified_name) = (
         qualified_name.unescaped().display(ctx.db()).to_string(),
         qualified_name.display(ctx.db()).to_string(),
@@ -84,7 +95,7 @@ fn render(
         StructKind::Tuple if should_add_parens => {
             render_tuple_lit(db, snippet_cap, &fields, &escaped_qualified_name)
         }
-        StructKind::Record if should_add_parens => {
+        StructKind::Record if should_add_braces => {
             render_record_lit(db, snippet_cap, &fields, &escaped_qualified_name)
         }
         _ => RenderedLiteral {
@@ -97,8 +108,8 @@ fn render(
         rendered.literal.push_str("$0");
     }
 
-    // only show name in label if not adding parens
-    if !should_add_parens {
+    // only show name in label if not adding parens or braces
+    if !should_add_parens || (matches!(kind, StructKind::Record) && !should_add_braces) {
         kind = StructKind::Unit;
     }
     let label = format_literal_label(&qualified_name, kind, snippet_cap);
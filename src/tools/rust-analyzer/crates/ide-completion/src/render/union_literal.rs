@@ -18,6 +18,12 @@ pub(crate) fn render_union_literal(
     path: Option<hir::ModPath>,
     local_name: Option<Name>,
 ) -> Option<CompletionItem> {
+    let add_braces = ctx.completion.config.enable_auto_brace;
+    // Without its braces, a union literal is just its path, which is already completed on its own
+    // unless it needs to be qualified.
+    if !add_braces && path.is_none() {
+        return None;
+    }
     let name = local_name.unwrap_or_else(|| un.name(ctx.db()));
 
     let (qualified_name, escaped_qualified_name) = match path {
@@ -26,8 +32,9 @@ pub(crate) fn render_union_literal(
             (name.unescaped().display(ctx.db()).to_string(), name.display(ctx.db()).to_string())
         }
     };
-    let label = format_literal_label(&name.to_smol_str(), StructKind::Record, ctx.snippet_cap());
-    let lookup = format_literal_lookup(&name.to_smol_str(), StructKind::Record);
+    let kind = if add_braces { StructKind::Record } else { StructKind::Unit };
+    let label = format_literal_label(&name.to_smol_str(), kind, ctx.snippet_cap());
+    let lookup = format_literal_lookup(&name.to_smol_str(), kind);
     let mut item = CompletionItem::new(
         CompletionItemKind::SymbolKind(SymbolKind::Union),
         ctx.source_range(),
@@ -43,7 +50,9 @@ pub(crate) fn render_union_literal(
         return None;
     }
 
-    let literal = if ctx.snippet_cap().is_some() {
+    let literal = if !add_braces {
+        escaped_qualified_name
+    } else if ctx.snippet_cap().is_some() {
         format!(
             "{} {{ ${{1|{}|}}: ${{2:()}} }}$0",
             escaped_qualified_name,
@@ -65,10 +65,20 @@ union Union { field: i32 }
     enable_imports_on_the_fly: true,
     enable_self_on_the_fly: true,
     enable_private_editable: false,
+    enable_completions_for_pattern_bindings: true,
+    enable_completions_for_use_tree: true,
+    enable_completions_for_unresolved_imports: true,
+    enable_completions_for_extern_crate: true,
+    enable_completions_for_use_aliases: true,
+    enable_completions_for_cfg_variants: false,
+    include_deprecated: true,
     enable_term_search: true,
     term_search_fuel: 200,
+    max_type_inference_depth: 32,
+    min_word_length_for_fuzzy: 3,
     full_function_signatures: false,
     callable: Some(CallableSnippets::FillArguments),
+    enable_auto_brace: true,
     snippet_cap: SnippetCap::new(true),
     insert_use: InsertUseConfig {
         granularity: ImportGranularity::Crate,
@@ -81,6 +91,7 @@ union Union { field: i32 }
     prefer_prelude: true,
     snippets: Vec::new(),
     limit: None,
+    preselect_first_item: true,
 };
 
 pub(crate) fn completion_list(ra_fixture: &str) -> String {
@@ -1,7 +1,13 @@
 //! Completion tests for expressions.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::{
+    tests::{
+        check_edit, check_empty, completion_list, completion_list_with_config, BASE_ITEMS_FIXTURE,
+        TEST_CONFIG,
+    },
+    CompletionConfig,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}{ra_fixture}"));
@@ -1309,3 +1315,77 @@ fn main() {
         "#]],
     );
 }
+
+#[test]
+fn use_alias_completion() {
+    let fixture = r#"
+mod foo {
+    pub struct Bar;
+}
+use foo::Bar as Baz;
+fn main() {
+    $0
+}
+"#;
+    let actual = completion_list_with_config(TEST_CONFIG, fixture, false, None);
+    expect![[r#"
+        fn main() fn()
+        md foo
+        st Baz    Bar
+        bt u32    u32
+    "#]]
+    .assert_eq(&actual);
+    let config = CompletionConfig { enable_completions_for_use_aliases: false, ..TEST_CONFIG };
+    let actual = completion_list_with_config(config, fixture, false, None);
+    expect![[r#"
+        fn main() fn()
+        md foo
+        bt u32    u32
+    "#]]
+    .assert_eq(&actual);
+}
+
+#[test]
+fn cfg_disabled_item_completion() {
+    let fixture = r#"
+//- /main.rs crate:main cfg:feature=std
+mod imp {
+    #[cfg(feature = "serde")]
+    pub struct Serializer;
+    #[cfg(feature = "std")]
+    pub fn with_std() {}
+    #[cfg(feature = "serde")]
+    pub fn shared() {}
+    #[cfg(not(feature = "serde"))]
+    pub fn shared() {}
+}
+#[cfg(feature = "serde")]
+fn with_serde() {}
+fn main() {
+    imp::$0
+}
+"#;
+    let actual = completion_list_with_config(TEST_CONFIG, fixture, false, None);
+    expect![[r#"
+        fn shared()   fn()
+        fn with_std() fn()
+    "#]]
+    .assert_eq(&actual);
+    let config = CompletionConfig { enable_completions_for_cfg_variants: true, ..TEST_CONFIG };
+    let actual = completion_list_with_config(config.clone(), fixture, false, None);
+    expect![[r#"
+        fn shared()   fn()
+        fn with_std() fn()
+        st Serializer inactive: feature = "serde" is disabled
+    "#]]
+    .assert_eq(&actual);
+    let actual =
+        completion_list_with_config(config, &fixture.replace("imp::$0", "$0"), false, None);
+    expect![[r#"
+        fn main()     fn()
+        fn with_serde inactive: feature = "serde" is disabled
+        md imp
+        bt u32        u32
+    "#]]
+    .assert_eq(&actual);
+}
@@ -3,10 +3,14 @@
 use crate::{
     context::{CompletionAnalysis, NameContext, NameKind, NameRefKind},
     tests::{check_edit, check_edit_with_config, TEST_CONFIG},
+    CompletionConfig,
 };
 
 fn check(ra_fixture: &str, expect: Expect) {
-    let config = TEST_CONFIG;
+    check_with_config(TEST_CONFIG, ra_fixture, expect);
+}
+
+fn check_with_config(config: CompletionConfig, ra_fixture: &str, expect: Expect) {
     let (db, position) = crate::tests::position(ra_fixture);
     let (ctx, analysis) = crate::context::CompletionContext::new(&db, position, &config).unwrap();
 
@@ -199,6 +203,35 @@ fn main() {
     );
 }
 
+#[test]
+fn short_paths_are_fuzzy_matched_with_lower_min_length() {
+    let fixture = r#"
+//- /lib.rs crate:dep
+pub struct Barc;
+pub struct Rcar;
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    rc$0
+}
+"#;
+    check(
+        fixture,
+        expect![[r#"
+            st Rcar (use dep::Rcar) Rcar
+        "#]],
+    );
+    let config = CompletionConfig { min_word_length_for_fuzzy: 2, ..TEST_CONFIG };
+    check_with_config(
+        config,
+        fixture,
+        expect![[r#"
+            st Rcar (use dep::Rcar) Rcar
+            st Barc (use dep::Barc) Barc
+        "#]],
+    );
+}
+
 #[test]
 fn fuzzy_completions_come_in_specific_order() {
     cov_mark::check!(certain_fuzzy_order_test);
@@ -1,7 +1,13 @@
 //! Completion tests for pattern position.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, check_empty, completion_list, BASE_ITEMS_FIXTURE};
+use crate::{
+    tests::{
+        check_edit, check_empty, completion_list, completion_list_with_config, BASE_ITEMS_FIXTURE,
+        TEST_CONFIG,
+    },
+    CompletionConfig,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(&format!("{BASE_ITEMS_FIXTURE}\n{ra_fixture}"));
@@ -137,6 +143,18 @@ fn foo() {
     );
 }
 
+#[test]
+fn refutable_with_pattern_completions_disabled() {
+    let config = CompletionConfig { enable_completions_for_pattern_bindings: false, ..TEST_CONFIG };
+    let actual = completion_list_with_config(
+        config,
+        &format!("{BASE_ITEMS_FIXTURE}\nfn foo() {{\n    if let a$0\n}}\n"),
+        true,
+        None,
+    );
+    expect![[""]].assert_eq(&actual);
+}
+
 #[test]
 fn irrefutable() {
     check(
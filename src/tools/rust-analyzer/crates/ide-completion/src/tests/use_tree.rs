@@ -1,7 +1,10 @@
 //! Completion tests for use trees.
 use expect_test::{expect, Expect};
 
-use crate::tests::completion_list;
+use crate::{
+    tests::{completion_list, completion_list_with_config, TEST_CONFIG},
+    CompletionConfig,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -35,6 +38,47 @@ fn use_tree_completion() {
     );
 }
 
+#[test]
+fn use_tree_completion_disabled() {
+    let config = CompletionConfig { enable_completions_for_use_tree: false, ..TEST_CONFIG };
+    let actual = completion_list_with_config(
+        config,
+        r#"
+struct implThing;
+
+use crate::{impl$0};
+"#,
+        true,
+        None,
+    );
+    expect![[""]].assert_eq(&actual);
+}
+
+#[test]
+fn unresolved_import_completion() {
+    let fixture = r#"
+mod foo {
+    pub use non_existent::Unresolved;
+    pub struct S;
+}
+use foo::$0;
+"#;
+    check(
+        fixture,
+        expect![[r#"
+            st S          S
+            ?? Unresolved
+        "#]],
+    );
+    let config =
+        CompletionConfig { enable_completions_for_unresolved_imports: false, ..TEST_CONFIG };
+    let actual = completion_list_with_config(config, fixture, true, None);
+    expect![[r#"
+        st S S
+    "#]]
+    .assert_eq(&actual);
+}
+
 #[test]
 fn use_tree_start() {
     cov_mark::check!(unqualified_path_selected_only);
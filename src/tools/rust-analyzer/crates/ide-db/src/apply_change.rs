@@ -2,10 +2,11 @@
 
 use base_db::{
     salsa::{
+        self,
         debug::{DebugQueryTable, TableEntry},
         Database, Durability, Query, QueryTable,
     },
-    SourceRootId,
+    FileId, SourceDatabaseExt, SourceRootId,
 };
 use profile::{memory_usage, Bytes};
 use rustc_hash::FxHashSet;
@@ -13,6 +14,15 @@
 
 use crate::{symbol_index::SymbolsDatabase, ChangeWithProcMacros, RootDatabase};
 
+/// What [`RootDatabase::collect_garbage`] freed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GcStats {
+    /// Number of memoized query results that were dropped.
+    pub freed_queries: usize,
+    /// Change of the allocated memory, if it went down.
+    pub freed_bytes: usize,
+}
+
 impl RootDatabase {
     pub fn request_cancellation(&mut self) {
         let _p =
@@ -41,6 +51,38 @@ pub fn apply_change(&mut self, change: ChangeWithProcMacros) {
         change.apply(self);
     }
 
+    /// Touches the text of `file_id` without changing it, moving the file to a
+    /// new revision.
+    ///
+    /// Queries depending on the file have to be re-validated on the next
+    /// access. Salsa still backdates results which turn out to be equal, so
+    /// this doesn't force their re-execution.
+    pub fn invalidate_file(&mut self, file_id: FileId) {
+        let _p = tracing::span!(tracing::Level::INFO, "RootDatabase::invalidate_file").entered();
+        let text = SourceDatabaseExt::file_text(self, file_id);
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text.to_string()));
+        self.apply_change(change);
+    }
+
+    /// Drops the memoized results of all queries which weren't verified in
+    /// the current revision, that is which weren't needed since the last
+    /// change. They are recomputed if they are needed again.
+    ///
+    /// Inputs and interned values are kept.
+    pub fn collect_garbage(&mut self) -> GcStats {
+        let _p = tracing::span!(tracing::Level::INFO, "RootDatabase::collect_garbage").entered();
+        let before = memory_usage().allocated;
+        let revision = self.salsa_runtime().current_revision();
+        let mut freed_queries = 0;
+        // Importing `DatabaseOps` would make `synthetic_write` above ambiguous.
+        salsa::plumbing::DatabaseOps::for_each_query(self, &mut |storage| {
+            freed_queries += storage.sweep(revision)
+        });
+        let freed_bytes = (before - memory_usage().allocated).bytes();
+        GcStats { freed_queries, freed_bytes: usize::try_from(freed_bytes).unwrap_or(0) }
+    }
+
     // Feature: Memory Usage
     //
     // Clears rust-analyzer's internal database and prints memory usage statistics.
@@ -44,9 +44,16 @@ pub mod syntax_helpers {
     pub use parser::LexedStr;
 }
 
+pub use apply_change::GcStats;
 pub use hir::ChangeWithProcMacros;
 
-use std::{fmt, mem::ManuallyDrop};
+use std::{
+    cell::RefCell,
+    fmt,
+    mem::ManuallyDrop,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
 
 use base_db::{
     salsa::{self, Durability},
@@ -84,6 +91,8 @@ pub struct RootDatabase {
     // which duplicates `Weak::drop` and `Arc::drop` tens of thousands of times, which makes
     // compile times of all `ide_*` and downstream crates suffer greatly.
     storage: ManuallyDrop<salsa::Storage<RootDatabase>>,
+    /// The number of queries executed so far, shared with all snapshots.
+    query_count: Arc<AtomicU64>,
 }
 
 impl Drop for RootDatabase {
@@ -131,7 +140,40 @@ fn relevant_crates(&self, file_id: FileId) -> Arc<[CrateId]> {
     }
 }
 
-impl salsa::Database for RootDatabase {}
+impl salsa::Database for RootDatabase {
+    fn salsa_event(&self, event: salsa::Event) {
+        if let salsa::EventKind::WillExecute { database_key } = event.kind {
+            self.query_count.fetch_add(1, Ordering::Relaxed);
+            if PROFILED_QUERIES.load(Ordering::Relaxed) != 0 {
+                EXECUTED_QUERIES.with(|it| {
+                    if let Some(executed) = &mut *it.borrow_mut() {
+                        executed.push(database_key);
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// The number of [`RootDatabase::profile_query`] calls in progress, so that executions are only
+/// recorded while there is one.
+static PROFILED_QUERIES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The queries executed on this thread while [`RootDatabase::profile_query`] runs one.
+    static EXECUTED_QUERIES: RefCell<Option<Vec<salsa::DatabaseKeyIndex>>> = const { RefCell::new(None) };
+}
+
+/// How long running a query took, and how much of it had to be recomputed, as returned by
+/// [`RootDatabase::profile_query`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryProfile {
+    pub wall_time: Duration,
+    /// Whether the memoized result of the query was reused instead of executing it again.
+    pub memo_hit: bool,
+    /// The number of other queries that were executed rather than reused while running it.
+    pub executed_dependencies: usize,
+}
 
 impl Default for RootDatabase {
     fn default() -> RootDatabase {
@@ -141,7 +183,10 @@ fn default() -> RootDatabase {
 
 impl RootDatabase {
     pub fn new(lru_capacity: Option<usize>) -> RootDatabase {
-        let mut db = RootDatabase { storage: ManuallyDrop::new(salsa::Storage::default()) };
+        let mut db = RootDatabase {
+            storage: ManuallyDrop::new(salsa::Storage::default()),
+            query_count: Default::default(),
+        };
         db.set_crate_graph_with_durability(Default::default(), Durability::HIGH);
         db.set_proc_macros_with_durability(Default::default(), Durability::HIGH);
         db.set_local_roots_with_durability(Default::default(), Durability::HIGH);
@@ -152,6 +197,56 @@ pub fn new(lru_capacity: Option<usize>) -> RootDatabase {
         db
     }
 
+    /// Returns the number of queries that were executed, rather than reused
+    /// from memoized results, since the database was created.
+    pub fn query_count(&self) -> u64 {
+        self.query_count.load(Ordering::Relaxed)
+    }
+
+    /// Runs the query `Q` for `key` once, and returns how long it took and
+    /// which part of it had to be executed. `table` returns the table of `Q`
+    /// in this database, like `|db| hir::db::MirBodyQuery.in_db(db)`.
+    pub fn profile_query<'a, Q>(
+        &'a self,
+        table: impl FnOnce(&'a Self) -> salsa::QueryTable<'a, Q>,
+        key: Q::Key,
+    ) -> QueryProfile
+    where
+        Q: salsa::Query + 'a,
+        Q::Storage: salsa::plumbing::QueryStorageOps<Q> + 'a,
+    {
+        struct Profiling;
+        impl Drop for Profiling {
+            fn drop(&mut self) {
+                EXECUTED_QUERIES.with(|it| *it.borrow_mut() = None);
+                PROFILED_QUERIES.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+
+        let table = table(self);
+        EXECUTED_QUERIES.with(|it| *it.borrow_mut() = Some(Vec::new()));
+        PROFILED_QUERIES.fetch_add(1, Ordering::Relaxed);
+        // Stops the recording also when the query unwinds from a cancellation.
+        let profiling = Profiling;
+        let start = Instant::now();
+        let value = table.get(key.clone());
+        let wall_time = start.elapsed();
+        drop(value);
+        let executed = EXECUTED_QUERIES.with(|it| it.borrow_mut().take()).unwrap_or_default();
+        drop(profiling);
+        // A `DatabaseKeyIndex` doesn't tell which key it stands for, other than through the
+        // `Debug` output of its query, which derived queries write like this.
+        let profiled = format!("{}::{}({key:?})", std::any::type_name::<Q>(), Q::QUERY_NAME);
+        let memo_hit = !executed.iter().any(|it| {
+            it.query_index() == Q::QUERY_INDEX && format!("{:?}", it.debug(self)) == profiled
+        });
+        QueryProfile {
+            wall_time,
+            memo_hit,
+            executed_dependencies: executed.len() - usize::from(!memo_hit),
+        }
+    }
+
     pub fn enable_proc_attr_macros(&mut self) {
         self.set_expand_proc_attr_macros_with_durability(true, Durability::HIGH);
     }
@@ -321,7 +416,10 @@ macro_rules! update_lru_capacity_per_query {
 
 impl salsa::ParallelDatabase for RootDatabase {
     fn snapshot(&self) -> salsa::Snapshot<RootDatabase> {
-        salsa::Snapshot::new(RootDatabase { storage: ManuallyDrop::new(self.storage.snapshot()) })
+        salsa::Snapshot::new(RootDatabase {
+            storage: ManuallyDrop::new(self.storage.snapshot()),
+            query_count: self.query_count.clone(),
+        })
     }
 }
 
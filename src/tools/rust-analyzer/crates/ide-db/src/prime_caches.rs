@@ -59,6 +59,9 @@ enum ParallelPrimeCacheWorkerProgress {
         EndCrate { crate_id: CrateId },
     }
 
+    // Declared before the work channel so that the channel is closed first when leaving, be it
+    // normally or by unwinding from a cancellation, which lets the workers exit before being joined.
+    let mut workers = Vec::with_capacity(num_worker_threads as usize);
     let (work_sender, progress_receiver) = {
         let (progress_sender, progress_receiver) = crossbeam_channel::unbounded();
         let (work_sender, work_receiver) = crossbeam_channel::unbounded();
@@ -80,11 +83,11 @@ enum ParallelPrimeCacheWorkerProgress {
             let worker = prime_caches_worker.clone();
             let db = db.snapshot();
 
-            stdx::thread::Builder::new(stdx::thread::ThreadIntent::Worker)
-                .allow_leak(true)
+            let handle = stdx::thread::Builder::new(stdx::thread::ThreadIntent::Worker)
                 .name("PrimeCaches".to_owned())
                 .spawn(move || Cancelled::catch(|| worker(db)))
                 .expect("failed to spawn thread");
+            workers.push(handle);
         }
 
         (work_sender, progress_receiver)
@@ -58,7 +58,7 @@
 mod view_memory_layout;
 mod view_mir;
 
-use std::panic::UnwindSafe;
+use std::{fmt, panic::UnwindSafe, time::Instant};
 
 use cfg::CfgOptions;
 use fetch_crates::CrateInfo;
@@ -68,7 +68,9 @@
         salsa::{self, ParallelDatabase},
         CrateOrigin, Env, FileLoader, FileSet, SourceDatabase, SourceDatabaseExt, VfsPath,
     },
-    prime_caches, symbol_index, FxHashMap, FxIndexSet, LineIndexDatabase,
+    prime_caches,
+    symbol_index::{self, SymbolsDatabase},
+    FxHashMap, FxIndexSet, LineIndexDatabase,
 };
 use syntax::SourceFile;
 use triomphe::Arc;
@@ -131,7 +133,7 @@
     search::{ReferenceCategory, SearchScope},
     source_change::{FileSystemEdit, SnippetEdit, SourceChange},
     symbol_index::Query,
-    RootDatabase, SymbolKind,
+    GcStats, QueryProfile, RootDatabase, SymbolKind,
 };
 pub use ide_diagnostics::{
     Diagnostic, DiagnosticCode, DiagnosticsConfig, ExprFillDefaultMode, Severity,
@@ -160,15 +162,28 @@ pub fn new(range: TextRange, info: T) -> RangeInfo<T> {
 #[derive(Debug)]
 pub struct AnalysisHost {
     db: RootDatabase,
+    last_change: Option<Instant>,
+    file_watchers: FileWatchers,
+}
+
+/// The callbacks registered with [`AnalysisHost::watch_file`], by the file
+/// they watch.
+#[derive(Default)]
+struct FileWatchers(FxHashMap<FileId, Vec<Box<dyn Fn() + Send + Sync>>>);
+
+impl fmt::Debug for FileWatchers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter().map(|(file_id, it)| (file_id, it.len()))).finish()
+    }
 }
 
 impl AnalysisHost {
     pub fn new(lru_capacity: Option<usize>) -> AnalysisHost {
-        AnalysisHost { db: RootDatabase::new(lru_capacity) }
+        AnalysisHost::with_database(RootDatabase::new(lru_capacity))
     }
 
     pub fn with_database(db: RootDatabase) -> AnalysisHost {
-        AnalysisHost { db }
+        AnalysisHost { db, last_change: None, file_watchers: FileWatchers::default() }
     }
 
     pub fn update_lru_capacity(&mut self, lru_capacity: Option<usize>) {
@@ -188,7 +203,87 @@ pub fn analysis(&self) -> Analysis {
     /// Applies changes to the current state of the world. If there are
     /// outstanding snapshots, they will be canceled.
     pub fn apply_change(&mut self, change: ChangeWithProcMacros) {
+        tracing::debug!("apply_change: {} files changed", change.source_change.files_changed.len());
+        tracing::trace!("apply_change: invalidating {:?}", change.source_change.roots);
+        let watched: FxIndexSet<FileId> = change
+            .source_change
+            .files_changed
+            .iter()
+            .map(|&(file_id, _)| file_id)
+            .filter(|file_id| self.file_watchers.0.contains_key(file_id))
+            .collect();
         self.db.apply_change(change);
+        self.last_change = Some(Instant::now());
+        for file_id in watched {
+            self.file_watchers.0[&file_id].iter().for_each(|callback| callback());
+        }
+    }
+
+    /// Registers `callback` to be called after each [`AnalysisHost::apply_change`]
+    /// that changes `file_id`, once the change has been applied.
+    pub fn watch_file(&mut self, file_id: FileId, callback: impl Fn() + Send + Sync + 'static) {
+        self.file_watchers.0.entry(file_id).or_default().push(Box::new(callback));
+    }
+
+    /// Sets the text of all `files` in a single change, so that they are all
+    /// updated in the same revision.
+    pub fn set_file_text_batch(&mut self, files: Vec<(FileId, Arc<str>)>) {
+        let mut change = ChangeWithProcMacros::new();
+        for (file_id, text) in files {
+            change.change_file(file_id, Some(text.to_string()));
+        }
+        self.apply_change(change);
+    }
+
+    /// Moves `file_id` to a new revision without changing its contents, so that
+    /// everything depending on it has to be re-validated. This is not a cold
+    /// cache: results that turn out unchanged are reused instead of recomputed.
+    /// If there are outstanding snapshots, they will be canceled.
+    pub fn invalidate_file(&mut self, file_id: FileId) {
+        self.db.invalidate_file(file_id);
+        self.last_change = Some(Instant::now());
+    }
+
+    /// Returns when the last change was applied through this host, or `None`
+    /// if there was none yet.
+    pub fn last_change_timestamp(&self) -> Option<Instant> {
+        self.last_change
+    }
+
+    /// Returns the number of files in all local and library source roots.
+    pub fn file_count(&self) -> usize {
+        let db = &self.db;
+        db.local_roots()
+            .iter()
+            .chain(db.library_roots().iter())
+            .map(|&root| db.source_root(root).iter().count())
+            .sum()
+    }
+
+    /// Returns the number of Salsa queries executed since the database was
+    /// created, including the ones executed through snapshots.
+    pub fn query_count(&self) -> u64 {
+        self.db.query_count()
+    }
+
+    /// Runs the query `Q` for `key` once, and returns how long it took and
+    /// whether its memoized result could be reused. `table` returns the table
+    /// of `Q` in the database, like `|db| hir::db::MirBodyQuery.in_db(db)`.
+    pub fn profile_query<'a, Q>(
+        &'a self,
+        table: impl FnOnce(&'a RootDatabase) -> salsa::QueryTable<'a, Q>,
+        key: Q::Key,
+    ) -> QueryProfile
+    where
+        Q: salsa::Query + 'a,
+        Q::Storage: salsa::plumbing::QueryStorageOps<Q> + 'a,
+    {
+        self.db.profile_query(table, key)
+    }
+
+    /// Drops the memoized query results that weren't needed since the last change.
+    pub fn gc(&mut self) -> GcStats {
+        self.db.collect_garbage()
     }
 
     /// NB: this clears the database
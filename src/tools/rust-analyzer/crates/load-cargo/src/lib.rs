@@ -2,7 +2,12 @@
 //! for incorporating changes.
 // Note, don't remove any public api from this. This API is consumed by external tools
 // to run rust-analyzer as a library.
-use std::{collections::hash_map::Entry, iter, mem, path::Path, sync};
+use std::{
+    collections::hash_map::Entry,
+    fmt, iter, mem,
+    path::{Path, PathBuf},
+    sync,
+};
 
 use crossbeam_channel::{unbounded, Receiver};
 use hir_expand::proc_macro::{
@@ -15,7 +20,8 @@
 };
 use itertools::Itertools;
 use proc_macro_api::{MacroDylib, ProcMacroServer};
-use project_model::{CargoConfig, PackageRoot, ProjectManifest, ProjectWorkspace};
+use project_model::{CargoConfig, ManifestPath, PackageRoot, ProjectManifest, ProjectWorkspace};
+use semver::Version;
 use span::Span;
 use tracing::{instrument, Level};
 use vfs::{file_set::FileSetConfig, loader::Handle, AbsPath, AbsPathBuf, VfsPath};
@@ -24,6 +30,52 @@ pub struct LoadCargoConfig {
     pub load_out_dirs_from_check: bool,
     pub with_proc_macro_server: ProcMacroServerChoice,
     pub prefill_caches: bool,
+    /// Only check that the workspace can be turned into a crate graph, without
+    /// reading any sources, running build scripts or spawning the proc-macro
+    /// server. The returned database is empty.
+    pub dry_run: bool,
+}
+
+/// What [`load_workspace_info_at`] reports while it loads a workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadProgress {
+    /// A step of loading, like running `cargo metadata` or the build scripts.
+    Message(String),
+    /// The workspace was loaded with another toolchain than the one its
+    /// `rust-toolchain` file asks for, so analysis results may be off.
+    ToolchainMismatchWarning { file: PathBuf, channel: String, toolchain: Version },
+}
+
+impl fmt::Display for LoadProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadProgress::Message(msg) => f.write_str(msg),
+            LoadProgress::ToolchainMismatchWarning { file, channel, toolchain } => write!(
+                f,
+                "{} requests the `{channel}` toolchain, but the workspace was loaded with rustc {toolchain}",
+                file.display()
+            ),
+        }
+    }
+}
+
+/// The kind of `Cargo.toml` a workspace was loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    /// A manifest with a `[workspace]` but no `[package]` of its own.
+    Virtual,
+    /// The manifest of a package, which may be the root of a workspace as well.
+    Package,
+}
+
+/// What [`load_workspace_info_at`] returns.
+pub struct WorkspaceInfo {
+    pub db: RootDatabase,
+    pub vfs: vfs::Vfs,
+    pub proc_macro_server: Option<ProcMacroServer>,
+    /// `None` if the workspace wasn't loaded from a `Cargo.toml`, like a
+    /// `rust-project.json`.
+    pub kind: Option<WorkspaceKind>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -39,16 +91,132 @@ pub fn load_workspace_at(
     load_config: &LoadCargoConfig,
     progress: &dyn Fn(String),
 ) -> anyhow::Result<(RootDatabase, vfs::Vfs, Option<ProcMacroServer>)> {
+    let WorkspaceInfo { db, vfs, proc_macro_server, kind: _ } =
+        load_workspace_info_at(root, cargo_config, load_config, &|it| progress(it.to_string()))?;
+    Ok((db, vfs, proc_macro_server))
+}
+
+/// Like [`load_workspace_at`], but also tells which kind of manifest the
+/// workspace was loaded from, and reports typed [`LoadProgress`] events
+/// instead of plain messages.
+pub fn load_workspace_info_at(
+    root: &Path,
+    cargo_config: &CargoConfig,
+    load_config: &LoadCargoConfig,
+    progress: &dyn Fn(LoadProgress),
+) -> anyhow::Result<WorkspaceInfo> {
+    let message = &|msg| progress(LoadProgress::Message(msg));
     let root = AbsPathBuf::assert_utf8(std::env::current_dir()?.join(root));
-    let root = ProjectManifest::discover_single(&root)?;
-    let mut workspace = ProjectWorkspace::load(root, cargo_config, progress)?;
+    let manifest = ProjectManifest::discover_single(&root)?;
+    let cargo_manifest = match &manifest {
+        ProjectManifest::CargoToml(path) => std::fs::read_to_string(&**path)
+            .ok()
+            .and_then(|it| it.parse::<toml::Table>().ok())
+            .map(|manifest| (path.clone(), manifest)),
+        _ => None,
+    };
+    let kind = cargo_manifest.as_ref().map(|(_, manifest)| {
+        if is_virtual_manifest(manifest) {
+            WorkspaceKind::Virtual
+        } else {
+            WorkspaceKind::Package
+        }
+    });
+    let mut workspace = ProjectWorkspace::load(manifest, cargo_config, message).map_err(|err| {
+        let missing = cargo_manifest
+            .as_ref()
+            .filter(|(_, manifest)| is_virtual_manifest(manifest))
+            .and_then(|(path, manifest)| Some((path, missing_workspace_member(path, manifest)?)));
+        match missing {
+            Some((path, member)) => err.context(format!(
+                "failed to load the virtual manifest {path}, its workspace member `{member}` has no `Cargo.toml`"
+            )),
+            None => err,
+        }
+    })?;
+
+    if let Some(warning) =
+        workspace.toolchain.as_ref().and_then(|toolchain| toolchain_mismatch(&root, toolchain))
+    {
+        tracing::warn!("{warning}");
+        progress(warning);
+    }
 
-    if load_config.load_out_dirs_from_check {
-        let build_scripts = workspace.run_build_scripts(cargo_config, progress)?;
+    if load_config.load_out_dirs_from_check && !load_config.dry_run {
+        let build_scripts = workspace.run_build_scripts(cargo_config, message)?;
         workspace.set_build_scripts(build_scripts)
     }
 
-    load_workspace(workspace, &cargo_config.extra_env, load_config)
+    let (db, vfs, proc_macro_server) =
+        load_workspace(workspace, &cargo_config.extra_env, load_config)?;
+    Ok(WorkspaceInfo { db, vfs, proc_macro_server, kind })
+}
+
+/// Checks whether a `Cargo.toml` is a virtual manifest, one which declares a
+/// `[workspace]` without a `[package]` of its own.
+fn is_virtual_manifest(manifest: &toml::Table) -> bool {
+    manifest.contains_key("workspace") && !manifest.contains_key("package")
+}
+
+/// Returns the first of the `workspace.members` of a manifest that names a
+/// directory without a `Cargo.toml`. Glob patterns are not expanded.
+fn missing_workspace_member(path: &ManifestPath, manifest: &toml::Table) -> Option<String> {
+    let members = manifest.get("workspace")?.get("members")?.as_array()?;
+    members
+        .iter()
+        .filter_map(toml::Value::as_str)
+        .filter(|member| !member.contains(['*', '?', '[']))
+        .find(|member| {
+            let member_manifest = path.parent().join(member).join("Cargo.toml");
+            !std::fs::metadata(member_manifest).is_ok_and(|it| it.is_file())
+        })
+        .map(ToOwned::to_owned)
+}
+
+/// Checks whether the workspace was loaded with the toolchain its `rust-toolchain`
+/// file asks for, returning a warning if it was not.
+fn toolchain_mismatch(root: &AbsPath, toolchain: &Version) -> Option<LoadProgress> {
+    let (file, channel) = Path::new(root).ancestors().find_map(|dir| {
+        ["rust-toolchain.toml", "rust-toolchain"].into_iter().find_map(|name| {
+            let file = dir.join(name);
+            let contents = std::fs::read_to_string(&file).ok()?;
+            Some((file, toolchain_channel(&contents)?))
+        })
+    })?;
+    if channel_matches(&channel, toolchain) {
+        return None;
+    }
+    Some(LoadProgress::ToolchainMismatchWarning { file, channel, toolchain: toolchain.clone() })
+}
+
+fn toolchain_channel(contents: &str) -> Option<String> {
+    match contents.parse::<toml::Table>() {
+        Ok(table) => Some(table.get("toolchain")?.get("channel")?.as_str()?.to_owned()),
+        // The legacy `rust-toolchain` file consists of just the channel name.
+        Err(_) => {
+            let channel = contents.trim();
+            (!channel.is_empty()).then(|| channel.to_owned())
+        }
+    }
+}
+
+fn channel_matches(channel: &str, toolchain: &Version) -> bool {
+    let pre = toolchain.pre.as_str();
+    if channel.starts_with("nightly") {
+        pre.starts_with("nightly")
+    } else if channel.starts_with("beta") {
+        pre.starts_with("beta")
+    } else if channel.starts_with("stable") {
+        pre.is_empty()
+    } else {
+        // An explicit version like `1.78` or `1.78.0`, anything we can't parse is
+        // given the benefit of the doubt.
+        let version = [toolchain.major, toolchain.minor, toolchain.patch];
+        channel
+            .split('.')
+            .zip(version)
+            .all(|(part, it)| part.parse::<u64>().map_or(true, |part| part == it))
+    }
 }
 
 #[instrument(skip_all)]
@@ -57,6 +225,11 @@ pub fn load_workspace(
     extra_env: &FxHashMap<String, String>,
     load_config: &LoadCargoConfig,
 ) -> anyhow::Result<(RootDatabase, vfs::Vfs, Option<ProcMacroServer>)> {
+    if load_config.dry_run {
+        validate_workspace(&ws, extra_env)?;
+        return Ok((RootDatabase::new(None), vfs::Vfs::default(), None));
+    }
+
     let (sender, receiver) = unbounded();
     let mut vfs = vfs::Vfs::default();
     let mut loader = {
@@ -129,6 +302,28 @@ pub fn load_workspace(
     Ok((db, vfs, proc_macro_server.ok()))
 }
 
+/// Builds the crate graph of `ws` with a loader that only checks whether files
+/// exist, failing if no crate root could be found.
+fn validate_workspace(
+    ws: &ProjectWorkspace,
+    extra_env: &FxHashMap<String, String>,
+) -> anyhow::Result<()> {
+    let mut vfs = vfs::Vfs::default();
+    let (crate_graph, _) = ws.to_crate_graph(
+        &mut |path: &AbsPath| {
+            let exists = std::fs::metadata(path).is_ok_and(|it| it.is_file());
+            let path = vfs::VfsPath::from(path.to_path_buf());
+            vfs.set_file_contents(path.clone(), exists.then(Vec::new));
+            vfs.file_id(&path)
+        },
+        extra_env,
+    );
+    if crate_graph.iter().next().is_none() {
+        anyhow::bail!("workspace at {} has no crates", ws.manifest_or_root());
+    }
+    Ok(())
+}
+
 #[derive(Default)]
 pub struct ProjectFolders {
     pub load: Vec<vfs::loader::Entry>,
@@ -432,6 +627,7 @@ fn test_loading_rust_analyzer() {
             load_out_dirs_from_check: false,
             with_proc_macro_server: ProcMacroServerChoice::None,
             prefill_caches: false,
+            dry_run: false,
         };
         let (db, _vfs, _proc_macro) =
             load_workspace_at(path, &cargo_config, &load_cargo_config, &|_| {}).unwrap();
@@ -441,6 +637,85 @@ fn test_loading_rust_analyzer() {
         assert!(n_crates > 20);
     }
 
+    #[test]
+    fn test_dry_run_loading_rust_analyzer() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().parent().unwrap();
+        let cargo_config = CargoConfig::default();
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: true,
+            with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+            prefill_caches: true,
+            dry_run: true,
+        };
+        let (db, vfs, proc_macro) =
+            load_workspace_at(path, &cargo_config, &load_cargo_config, &|_| {}).unwrap();
+
+        assert_eq!(db.crate_graph().iter().count(), 0);
+        assert_eq!(vfs.iter().count(), 0);
+        assert!(proc_macro.is_none());
+    }
+
+    #[test]
+    fn test_workspace_kinds() {
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let cargo_config = CargoConfig::default();
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: false,
+            with_proc_macro_server: ProcMacroServerChoice::None,
+            prefill_caches: false,
+            dry_run: true,
+        };
+        let kind = |path: &Path| {
+            load_workspace_info_at(path, &cargo_config, &load_cargo_config, &|_| {}).unwrap().kind
+        };
+
+        assert_eq!(
+            kind(crate_dir.parent().unwrap().parent().unwrap()),
+            Some(WorkspaceKind::Virtual)
+        );
+        assert_eq!(kind(crate_dir), Some(WorkspaceKind::Package));
+    }
+
+    #[test]
+    fn toolchain_channels() {
+        assert_eq!(
+            toolchain_channel("nightly-2024-06-01\n").as_deref(),
+            Some("nightly-2024-06-01")
+        );
+        assert_eq!(
+            toolchain_channel("[toolchain]\nchannel = \"1.78\"\ncomponents = [\"rustfmt\"]\n")
+                .as_deref(),
+            Some("1.78")
+        );
+        assert_eq!(toolchain_channel("[toolchain]\npath = \"/opt/rust\"\n"), None);
+    }
+
+    #[test]
+    fn virtual_manifests() {
+        let is_virtual = |manifest: &str| is_virtual_manifest(&manifest.parse().unwrap());
+        assert!(is_virtual("[workspace]\nmembers = [\"crates/*\"]\n"));
+        assert!(is_virtual("[workspace.dependencies]\nserde = \"1\"\n"));
+        assert!(!is_virtual("[package]\nname = \"foo\"\n\n[workspace]\nmembers = [\"bar\"]\n"));
+        assert!(!is_virtual("[package]\nname = \"foo\"\n\n[[bin]]\nname = \"foo\"\n"));
+        // Tables can also be written inline, which a scan of the headers misses.
+        assert!(!is_virtual("package = { name = \"foo\" }\n\n[workspace]\n"));
+    }
+
+    #[test]
+    fn missing_workspace_members() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().parent().unwrap();
+        let path =
+            ManifestPath::try_from(AbsPathBuf::assert_utf8(root.join("Cargo.toml"))).unwrap();
+        let manifest =
+            |members: &str| format!("[workspace]\nmembers = {members}\n").parse().unwrap();
+        let missing = |members| missing_workspace_member(&path, &manifest(members));
+        assert_eq!(missing(r#"["crates/load-cargo", "crates/*"]"#), None);
+        assert_eq!(
+            missing(r#"["crates/load-cargo", "crates/gone"]"#).as_deref(),
+            Some("crates/gone")
+        );
+    }
+
     #[test]
     fn unrelated_sources() {
         let mut builder = FileSetConfigBuilder::default();
@@ -99,6 +99,10 @@ impl Bytes {
     pub fn megabytes(self) -> isize {
         self.0 / 1024 / 1024
     }
+
+    pub fn bytes(self) -> isize {
+        self.0
+    }
 }
 
 impl fmt::Display for Bytes {
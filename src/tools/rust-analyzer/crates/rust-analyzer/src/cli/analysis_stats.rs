@@ -80,6 +80,7 @@ pub fn run(self, verbosity: Verbosity) -> anyhow::Result<()> {
             load_out_dirs_from_check: !self.disable_build_scripts,
             with_proc_macro_server: ProcMacroServerChoice::Sysroot,
             prefill_caches: false,
+            dry_run: false,
         };
 
         let build_scripts_time = if self.disable_build_scripts {
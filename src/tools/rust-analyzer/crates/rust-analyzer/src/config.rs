@@ -457,20 +457,36 @@
         /// Term search fuel in "units of work" for assists (Defaults to 400).
         assist_termSearch_fuel: usize = 400,
 
+        /// Whether to add braces and field placeholders when completing record struct, variant and union literals.
+        completion_autoBrace_enable: bool = true,
         /// Toggles the additional completions that automatically add imports when completed.
         /// Note that your client must specify the `additionalTextEdits` LSP client capability to truly have this feature enabled.
         completion_autoimport_enable: bool       = true,
+        /// Typed names shorter than this many characters are only matched as a prefix of the items
+        /// to import, instead of fuzzily, which returns too many unrelated items for them.
+        completion_autoimport_fuzzyMinLength: usize = 3,
         /// Toggles the additional completions that automatically show method calls and field accesses
         /// with `self` prefixed to them when inside a method.
         completion_autoself_enable: bool        = true,
         /// Whether to add parenthesis and argument snippets when completing function.
         completion_callable_snippets: CallableCompletionDef  = CallableCompletionDef::FillArguments,
+        /// Whether to also show items that are left out by their `#[cfg]` attribute, like the ones behind a disabled feature.
+        completion_cfgVariants_enable: bool = false,
+        /// Whether to show items marked `#[deprecated]` in completion lists.
+        completion_deprecated_enable: bool = true,
+        /// Whether to show crate names after `extern crate`. Searching the crate graph for them can be slow
+        /// in large workspaces, and `extern crate` is rarely needed since the 2018 edition.
+        completion_externCrate_enable: bool = true,
         /// Whether to show full function/method signatures in completion docs.
         completion_fullFunctionSignatures_enable: bool = false,
         /// Maximum number of completions to return. If `None`, the limit is infinite.
         completion_limit: Option<usize> = None,
+        /// Whether to show completions in pattern positions, like constructors and bindings in `let` and `match` patterns.
+        completion_patterns_enable: bool        = true,
         /// Whether to show postfix snippets like `dbg`, `if`, `not`, etc.
         completion_postfix_enable: bool         = true,
+        /// Whether to mark the most relevant completion item as preselected. At most one item of a completion list is marked.
+        completion_preselectFirstItem_enable: bool = true,
         /// Enables completions of private items and fields that are defined in the current workspace even if they are not visible at the current position.
         completion_privateEditable_enable: bool = false,
         /// Custom completion snippets.
@@ -520,6 +536,15 @@
         completion_termSearch_enable: bool = false,
         /// Term search fuel in "units of work" for autocompletion (Defaults to 200).
         completion_termSearch_fuel: usize = 200,
+        /// Maximum nesting depth of the type expected at the cursor. Completions for deeper types are
+        /// not ranked by their type, which keeps deeply nested generics from slowing them down.
+        completion_typeInference_maxDepth: usize = 32,
+        /// Whether to show completions for names brought in by imports that failed to resolve.
+        completion_unresolvedImports_enable: bool = true,
+        /// Whether to show the names of items imported under an alias, like `Baz` in `use foo::Bar as Baz;`.
+        completion_useAliases_enable: bool = true,
+        /// Whether to show completions inside `use` declarations.
+        completion_useTree_enable: bool = true,
 
         /// Enables highlighting of related references while the cursor is on `break`, `loop`, `while`, or `for` keywords.
         highlightRelated_breakPoints_enable: bool = true,
@@ -1031,8 +1056,30 @@ pub fn completion(&self, source_root: Option<SourceRootId>) -> CompletionConfig
                 && completion_item_edit_resolve(&self.caps),
             enable_self_on_the_fly: self.completion_autoself_enable(source_root).to_owned(),
             enable_private_editable: self.completion_privateEditable_enable(source_root).to_owned(),
+            enable_completions_for_pattern_bindings: self
+                .completion_patterns_enable(source_root)
+                .to_owned(),
+            enable_completions_for_use_tree: self.completion_useTree_enable(source_root).to_owned(),
+            enable_completions_for_unresolved_imports: self
+                .completion_unresolvedImports_enable(source_root)
+                .to_owned(),
+            enable_completions_for_extern_crate: self
+                .completion_externCrate_enable(source_root)
+                .to_owned(),
+            enable_completions_for_use_aliases: self
+                .completion_useAliases_enable(source_root)
+                .to_owned(),
+            enable_completions_for_cfg_variants: self
+                .completion_cfgVariants_enable(source_root)
+                .to_owned(),
+            include_deprecated: self.completion_deprecated_enable(source_root).to_owned(),
             enable_term_search: self.completion_termSearch_enable(source_root).to_owned(),
             term_search_fuel: self.completion_termSearch_fuel(source_root).to_owned() as u64,
+            max_type_inference_depth: self.completion_typeInference_maxDepth(source_root).to_owned()
+                as u32,
+            min_word_length_for_fuzzy: self
+                .completion_autoimport_fuzzyMinLength(source_root)
+                .to_owned(),
             full_function_signatures: self
                 .completion_fullFunctionSignatures_enable(source_root)
                 .to_owned(),
@@ -1041,6 +1088,7 @@ pub fn completion(&self, source_root: Option<SourceRootId>) -> CompletionConfig
                 CallableCompletionDef::AddParentheses => Some(CallableSnippets::AddParentheses),
                 CallableCompletionDef::None => None,
             },
+            enable_auto_brace: self.completion_autoBrace_enable(source_root).to_owned(),
             snippet_cap: SnippetCap::new(try_or_def!(
                 self.caps
                     .text_document
@@ -1056,6 +1104,7 @@ pub fn completion(&self, source_root: Option<SourceRootId>) -> CompletionConfig
             prefer_prelude: self.imports_preferPrelude(source_root).to_owned(),
             snippets: self.snippets.clone().to_vec(),
             limit: self.completion_limit(source_root).to_owned(),
+            preselect_first_item: self.completion_preselectFirstItem_enable(source_root).to_owned(),
         }
     }
 
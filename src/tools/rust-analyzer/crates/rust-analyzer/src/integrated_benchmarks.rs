@@ -10,19 +10,50 @@
 //! in release mode in VS Code. There's however "rust-analyzer: Copy Run Command Line"
 //! which you can use to paste the command in terminal and add `--release` manually.
 
-use hir::ChangeWithProcMacros;
+use std::{
+    cell::RefCell,
+    cmp, fmt,
+    sync::{Mutex, Once},
+    time::{Duration, Instant},
+};
+
+use hir::{
+    db::{DefDatabase, ExpandDatabase, HirDatabase},
+    ChangeWithProcMacros,
+};
 use ide::{
-    AnalysisHost, CallableSnippets, CompletionConfig, DiagnosticsConfig, FilePosition, TextSize,
+    AdjustmentHints, AdjustmentHintsMode, Analysis, AnalysisHost, AssistConfig, AssistKind,
+    AssistResolveStrategy, CallableSnippets, ClosureReturnTypeHints, CompletionConfig,
+    CompletionItem, CompletionItemKind, DiagnosticCode, DiagnosticsConfig, DiscriminantHints,
+    FileId, FilePosition, FileRange, HighlightConfig, HighlightRelatedConfig, HoverConfig,
+    HoverDocFormat, InlayFieldsToResolve, InlayHintsConfig, LifetimeElisionHints, Query, Severity,
+    SingleResolve, Snippet, SnippetScope, SymbolKind, TextRange, TextSize,
 };
 use ide_db::{
+    base_db::SourceDatabase,
     imports::insert_use::{ImportGranularity, InsertUseConfig},
-    SnippetCap,
+    FxHashMap, SnippetCap,
 };
+use itertools::Itertools;
 use project_model::CargoConfig;
+use stdx::format_to;
+use syntax::{
+    ast::{self, HasName},
+    AstNode,
+};
 use test_utils::project_root;
-use vfs::{AbsPathBuf, VfsPath};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::{
+    filter::Targets,
+    layer::{Context, SubscriberExt},
+    Layer, Registry,
+};
+use triomphe::Arc;
+use vfs::{AbsPathBuf, Vfs, VfsPath};
 
-use load_cargo::{load_workspace_at, LoadCargoConfig, ProcMacroServerChoice};
+use load_cargo::{
+    load_workspace_info_at, LoadCargoConfig, LoadProgress, ProcMacroServerChoice, WorkspaceInfo,
+};
 
 #[test]
 fn integrated_highlighting_benchmark() {
@@ -42,13 +73,22 @@ fn integrated_highlighting_benchmark() {
         load_out_dirs_from_check: true,
         with_proc_macro_server: ProcMacroServerChoice::Sysroot,
         prefill_caches: false,
+        dry_run: false,
     };
 
-    let (db, vfs, _proc_macro) = {
+    enable_load_backtraces();
+    let WorkspaceInfo { db, vfs, .. } = {
         let _it = stdx::timeit("workspace loading");
-        load_workspace_at(&workspace_to_load, &cargo_config, &load_cargo_config, &|_| {}).unwrap()
+        load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap()
     };
     let mut host = AnalysisHost::with_database(db);
+    report("files", host.file_count());
 
     let file_id = {
         let file = workspace_to_load.join(file);
@@ -63,12 +103,16 @@ fn integrated_highlighting_benchmark() {
     }
 
     {
-        let _it = stdx::timeit("change");
-        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
-        text = text.replace(
-            "self.data.cargo_buildScripts_rebuildOnSave",
-            "self. data. cargo_buildScripts_rebuildOnSave",
-        );
+        let text = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            text = text.replace(
+                "self.data.cargo_buildScripts_rebuildOnSave",
+                "self. data. cargo_buildScripts_rebuildOnSave",
+            );
+            text
+        };
+        let _it = stdx::timeit("apply_change");
         let mut change = ChangeWithProcMacros::new();
         change.change_file(file_id, Some(text));
         host.apply_change(change);
@@ -76,12 +120,21 @@ fn integrated_highlighting_benchmark() {
 
     let _g = crate::tracing::hprof::init("*>20");
 
+    let queries = host.query_count();
     {
         let _it = stdx::timeit("after change");
         let _span = profile::cpu_span();
         let analysis = host.analysis();
         analysis.highlight_as_html(file_id, false).unwrap();
     }
+    // The change only touches whitespace inside a function body, so most of the
+    // crate's analysis has to be validated rather than executed again.
+    let executed = host.query_count() - queries;
+    report("queries executed after change", executed);
+    // 2029 queries were executed when this was measured on rust-analyzer's own
+    // sources, the same on every run. The margin of 25% leaves room for the
+    // file and its dependencies to grow.
+    assert!(executed <= 2_550, "re-analysis executed {executed} queries, expected about 2029");
 }
 
 #[test]
@@ -102,13 +155,22 @@ fn integrated_completion_benchmark() {
         load_out_dirs_from_check: true,
         with_proc_macro_server: ProcMacroServerChoice::Sysroot,
         prefill_caches: true,
+        dry_run: false,
     };
 
-    let (db, vfs, _proc_macro) = {
+    enable_load_backtraces();
+    let WorkspaceInfo { db, vfs, .. } = {
         let _it = stdx::timeit("workspace loading");
-        load_workspace_at(&workspace_to_load, &cargo_config, &load_cargo_config, &|_| {}).unwrap()
+        load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap()
     };
     let mut host = AnalysisHost::with_database(db);
+    report("files", host.file_count());
 
     let file_id = {
         let file = workspace_to_load.join(file);
@@ -119,11 +181,15 @@ fn integrated_completion_benchmark() {
     // kick off parsing and index population
 
     let completion_offset = {
-        let _it = stdx::timeit("change");
-        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
-        let completion_offset =
-            patch(&mut text, "db.struct_data(self.id)", "sel;\ndb.struct_data(self.id)")
-                + "sel".len();
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let completion_offset =
+                patch(&mut text, "db.struct_data(self.id)", "sel;\ndb.struct_data(self.id)")
+                    + "sel".len();
+            (text, completion_offset)
+        };
+        let _it = stdx::timeit("apply_change");
         let mut change = ChangeWithProcMacros::new();
         change.change_file(file_id, Some(text));
         host.apply_change(change);
@@ -138,10 +204,20 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_completions_for_pattern_bindings: true,
+            enable_completions_for_use_tree: true,
+            enable_completions_for_unresolved_imports: true,
+            enable_completions_for_extern_crate: true,
+            enable_completions_for_use_aliases: true,
+            enable_completions_for_cfg_variants: false,
+            include_deprecated: true,
             enable_term_search: true,
             term_search_fuel: 200,
+            max_type_inference_depth: 32,
+            min_word_length_for_fuzzy: 3,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
+            enable_auto_brace: true,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -154,6 +230,7 @@ fn integrated_completion_benchmark() {
             prefer_prelude: true,
             snippets: Vec::new(),
             limit: None,
+            preselect_first_item: true,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
@@ -163,11 +240,15 @@ fn integrated_completion_benchmark() {
     let _g = crate::tracing::hprof::init("*");
 
     let completion_offset = {
-        let _it = stdx::timeit("change");
-        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
-        let completion_offset =
-            patch(&mut text, "sel;\ndb.struct_data(self.id)", ";sel;\ndb.struct_data(self.id)")
-                + ";sel".len();
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let completion_offset =
+                patch(&mut text, "sel;\ndb.struct_data(self.id)", ";sel;\ndb.struct_data(self.id)")
+                    + ";sel".len();
+            (text, completion_offset)
+        };
+        let _it = stdx::timeit("apply_change");
         let mut change = ChangeWithProcMacros::new();
         change.change_file(file_id, Some(text));
         host.apply_change(change);
@@ -183,10 +264,20 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_completions_for_pattern_bindings: true,
+            enable_completions_for_use_tree: true,
+            enable_completions_for_unresolved_imports: true,
+            enable_completions_for_extern_crate: true,
+            enable_completions_for_use_aliases: true,
+            enable_completions_for_cfg_variants: false,
+            include_deprecated: true,
             enable_term_search: true,
             term_search_fuel: 200,
+            max_type_inference_depth: 32,
+            min_word_length_for_fuzzy: 3,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
+            enable_auto_brace: true,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -199,6 +290,7 @@ fn integrated_completion_benchmark() {
             prefer_prelude: true,
             snippets: Vec::new(),
             limit: None,
+            preselect_first_item: true,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
@@ -206,11 +298,17 @@ fn integrated_completion_benchmark() {
     }
 
     let completion_offset = {
-        let _it = stdx::timeit("change");
-        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
-        let completion_offset =
-            patch(&mut text, "sel;\ndb.struct_data(self.id)", "self.;\ndb.struct_data(self.id)")
-                + "self.".len();
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let completion_offset = patch(
+                &mut text,
+                "sel;\ndb.struct_data(self.id)",
+                "self.;\ndb.struct_data(self.id)",
+            ) + "self.".len();
+            (text, completion_offset)
+        };
+        let _it = stdx::timeit("apply_change");
         let mut change = ChangeWithProcMacros::new();
         change.change_file(file_id, Some(text));
         host.apply_change(change);
@@ -226,10 +324,20 @@ fn integrated_completion_benchmark() {
             enable_imports_on_the_fly: true,
             enable_self_on_the_fly: true,
             enable_private_editable: true,
+            enable_completions_for_pattern_bindings: true,
+            enable_completions_for_use_tree: true,
+            enable_completions_for_unresolved_imports: true,
+            enable_completions_for_extern_crate: true,
+            enable_completions_for_use_aliases: true,
+            enable_completions_for_cfg_variants: false,
+            include_deprecated: true,
             enable_term_search: true,
             term_search_fuel: 200,
+            max_type_inference_depth: 32,
+            min_word_length_for_fuzzy: 3,
             full_function_signatures: false,
             callable: Some(CallableSnippets::FillArguments),
+            enable_auto_brace: true,
             snippet_cap: SnippetCap::new(true),
             insert_use: InsertUseConfig {
                 granularity: ImportGranularity::Crate,
@@ -242,6 +350,7 @@ fn integrated_completion_benchmark() {
             prefer_prelude: true,
             snippets: Vec::new(),
             limit: None,
+            preselect_first_item: true,
         };
         let position =
             FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
@@ -267,13 +376,22 @@ fn integrated_diagnostics_benchmark() {
         load_out_dirs_from_check: true,
         with_proc_macro_server: ProcMacroServerChoice::Sysroot,
         prefill_caches: true,
+        dry_run: false,
     };
 
-    let (db, vfs, _proc_macro) = {
+    enable_load_backtraces();
+    let WorkspaceInfo { db, vfs, .. } = {
         let _it = stdx::timeit("workspace loading");
-        load_workspace_at(&workspace_to_load, &cargo_config, &load_cargo_config, &|_| {}).unwrap()
+        load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap()
     };
     let mut host = AnalysisHost::with_database(db);
+    report("files", host.file_count());
 
     let file_id = {
         let file = workspace_to_load.join(file);
@@ -307,9 +425,13 @@ fn integrated_diagnostics_benchmark() {
     let _g = crate::tracing::hprof::init("*");
 
     {
-        let _it = stdx::timeit("change");
-        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
-        patch(&mut text, "db.struct_data(self.id)", "();\ndb.struct_data(self.id)");
+        let text = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            patch(&mut text, "db.struct_data(self.id)", "();\ndb.struct_data(self.id)");
+            text
+        };
+        let _it = stdx::timeit("apply_change");
         let mut change = ChangeWithProcMacros::new();
         change.change_file(file_id, Some(text));
         host.apply_change(change);
@@ -324,6 +446,3620 @@ fn integrated_diagnostics_benchmark() {
     }
 }
 
+#[test]
+fn integrated_builtin_type_hover_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let primitives = ["u8", "u16", "u32", "u64", "usize", "i32", "bool", "char", "str", "f64"];
+    let params = primitives.map(|ty| format!("_: &{ty}")).join(", ");
+    let snippet =
+        format!("\nstruct HoverBenchStruct;\nfn hover_bench({params}, _: HoverBenchStruct) {{}}\n");
+    let start = append_to_file(&mut host, file_id, &snippet);
+    let offset_of = |needle: &str| start + TextSize::of(&snippet[..snippet.find(needle).unwrap()]);
+
+    let config = hover_config();
+    let analysis = host.analysis();
+    let hover_at = |offset: TextSize| {
+        let range = FileRange { file_id, range: TextRange::empty(offset) };
+        let it = Instant::now();
+        analysis.hover(&config, range).unwrap().expect("no hover");
+        it.elapsed()
+    };
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "builtin type hover").entered();
+        for ty in primitives {
+            let elapsed = hover_at(offset_of(&format!("{ty}, ")));
+            report(format_args!("hover `{ty}`"), format_args!("{elapsed:.2?}"));
+        }
+    }
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "struct hover").entered();
+        let elapsed = hover_at(offset_of("HoverBenchStruct)"));
+        report("hover `HoverBenchStruct`", format_args!("{elapsed:.2?}"));
+    }
+}
+
+#[test]
+fn integrated_common_prefix_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let methods = (0..300).map(|i| format!("    fn get_{i}(&self) {{}}")).join("\n");
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        &format!(
+            "\nstruct PrefixBench;\nimpl PrefixBench {{\n{methods}\n}}\n\
+             fn prefix_bench(it: PrefixBench) {{\n    it.get_$0\n}}\n"
+        ),
+    );
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "common prefix completion").entered();
+        let _span = profile::cpu_span();
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        report("completion items", items.map_or(0, |it| it.len()));
+    }
+}
+
+#[test]
+fn integrated_return_type_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn return_type_bench(it: &[u32]) -> $0 {\n    \
+         it.iter().map(|&x| (x, x.to_string())).collect::<Vec<_>>()\n}\n",
+    );
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "return type completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        report("return type completion", format_args!("{:.2?}", it.elapsed()));
+        report("completion items", items.map_or(0, |it| it.len()));
+    }
+}
+
+#[test]
+fn integrated_branch_switch_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    // Switching branches touches lots of files at once, so change every other
+    // Rust file of the workspace in a single `Change`.
+    let root = AbsPathBuf::assert_utf8(project_root());
+    let workspace_files = vfs
+        .iter()
+        .filter(|&(id, path)| {
+            id != file_id
+                && path.as_path().map_or(false, |it| it.starts_with(&root))
+                && path.name_and_extension().map_or(false, |(_, ext)| ext == Some("rs"))
+        })
+        .map(|(id, _)| id)
+        .collect::<Vec<_>>();
+    let files = workspace_files
+        .iter()
+        .step_by(2)
+        .map(|&id| {
+            let text = host.analysis().file_text(id).unwrap();
+            (id, Arc::from(format!("{text}\n// switched branch\n")))
+        })
+        .collect::<Vec<_>>();
+    report(
+        "changed files",
+        format_args!("{} of {}", workspace_files.len().div_ceil(2), workspace_files.len()),
+    );
+
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn branch_switch_bench(db: &dyn HirDatabase) {\n    db.$0\n}\n",
+    );
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "branch switch completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        host.set_file_text_batch(files);
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        report("branch switch completion", format_args!("{:.2?}", it.elapsed()));
+        report("completion items", items.map_or(0, |it| it.len()));
+    }
+}
+
+#[test]
+fn integrated_invalidated_file_highlighting_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) =
+        load_rust_analyzer("./crates/rust-analyzer/src/config.rs", false);
+
+    {
+        let _it = stdx::timeit("initial");
+        host.analysis().highlight_as_html(file_id, false).unwrap();
+    }
+
+    {
+        let _it = stdx::timeit("warm");
+        host.analysis().highlight_as_html(file_id, false).unwrap();
+    }
+
+    // The text doesn't change, so salsa backdates every result that re-validates to the same value.
+    // This measures the re-validation of the memoized results, not a cold cache.
+    host.invalidate_file(file_id);
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "invalidated file re-validation").entered();
+        let _it = stdx::timeit("re-validation after invalidation");
+        let _span = profile::cpu_span();
+        host.analysis().highlight_as_html(file_id, false).unwrap();
+    }
+}
+
+#[test]
+fn integrated_parsing_throughput_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (host, vfs, _file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", false);
+
+    let files = vfs
+        .iter()
+        .filter(|(_, path)| path.name_and_extension().map_or(false, |(_, ext)| ext == Some("rs")))
+        .map(|(id, _)| id)
+        .collect::<Vec<_>>();
+    let analysis = host.analysis();
+    let bytes: usize = files.iter().map(|&id| analysis.file_text(id).unwrap().len()).sum();
+
+    let elapsed = {
+        let _p = tracing::span!(tracing::Level::INFO, "parse all files").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        for &id in &files {
+            analysis.parse(id).unwrap();
+        }
+        it.elapsed()
+    };
+
+    let megabytes = bytes as f64 / (1024.0 * 1024.0);
+    report("parsed files", files.len());
+    report("parsed bytes", bytes);
+    report("parsing", format_args!("{elapsed:.2?}"));
+    report("throughput", format_args!("{:.2} MB/s", megabytes / elapsed.as_secs_f64()));
+}
+
+#[test]
+fn integrated_symbol_prefix_vs_substring_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (host, _vfs, _file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let analysis = host.analysis();
+
+    {
+        let _it = stdx::timeit("symbol index");
+        analysis.symbol_search(Query::new("Struct".to_owned()), 128).unwrap();
+    }
+
+    let mut prefix = Query::new("Struct".to_owned());
+    prefix.prefix();
+    // There is no dedicated substring mode, fuzzy search matches substrings as well.
+    let substring = Query::new("ruct".to_owned());
+
+    for (label, query) in
+        [("prefix search `Struct`", prefix), ("substring search `ruct`", substring)]
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "symbol search", label).entered();
+        let it = Instant::now();
+        let results = analysis.symbol_search(query, 128).unwrap();
+        report(label, format_args!("{:.2?} ({} results)", it.elapsed(), results.len()));
+    }
+}
+
+#[test]
+fn integrated_inlay_hints_type_change_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    append_to_file(
+        &mut host,
+        file_id,
+        "\nfn inlay_hints_bench() {\n    let value: u32 = 0;\n    let doubled = value * 2;\n    \
+         let items = vec![doubled; 3];\n    let first = items.first().copied();\n}\n",
+    );
+
+    let config = inlay_hints_config();
+    let inlay_hints = |host: &AnalysisHost| {
+        let it = Instant::now();
+        let hints = host.analysis().inlay_hints(&config, file_id, None).unwrap();
+        (it.elapsed(), hints.len())
+    };
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "initial inlay hints").entered();
+        let (elapsed, hints) = inlay_hints(&host);
+        report("initial inlay hints", format_args!("{elapsed:.2?} ({hints} hints)"));
+    }
+
+    {
+        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+        patch(&mut text, "let value: u32", "let value: u64");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+    }
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "inlay hints after type change").entered();
+        let _span = profile::cpu_span();
+        let (elapsed, hints) = inlay_hints(&host);
+        report("inlay hints after type change", format_args!("{elapsed:.2?} ({hints} hints)"));
+    }
+}
+
+#[test]
+fn integrated_pattern_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let snippets = [
+        (
+            "pattern completion",
+            "\nfn pattern_bench(it: Option<Crate>) {\n    match it {\n        S$0\n    }\n}\n",
+        ),
+        ("expression completion", "\nfn expression_bench() {\n    let _ = S$0;\n}\n"),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_impl_body_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let fields = (0..20).map(|i| format!("    field_{i}: u32,")).join("\n");
+    append_to_file(
+        &mut host,
+        file_id,
+        &format!(
+            "\nstruct ImplBench {{\n{fields}\n}}\n\
+             impl ImplBench {{\n    fn new() -> Self {{ todo!() }}\n}}\n"
+        ),
+    );
+
+    let snippets = [
+        (
+            "impl body completion",
+            "\nimpl ImplBench {\n    fn method(&self) {\n        f$0\n    }\n}\n",
+        ),
+        ("free function completion", "\nfn free_function(it: &ImplBench) {\n    f$0\n}\n"),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_generic_env_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let params = (0..12).map(|i| format!("T{i}")).join(", ");
+    let args = (0..12).map(|i| format!("arg_{i}: T{i}")).join(", ");
+    let bounds = (0..12)
+        .map(|i| format!("    T{i}: Clone + std::fmt::Debug + Iterator<Item = u32>,"))
+        .join("\n");
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        &format!("\nfn generic_bench<{params}>({args})\nwhere\n{bounds}\n{{\n    arg_0.$0\n}}\n"),
+    );
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "generic env completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        let items = items.map_or(0, |it| it.len());
+        report("generic env completion", format_args!("{:.2?} ({items} items)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_dyn_alias_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let completion_offset = {
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let prefix = "trait DynAliasBench { fn dyn_alias_method(&self) -> u32; }\n\
+                          type Boxed = Box<dyn DynAliasBench>;\n\
+                          fn dyn_alias_bench(boxed: Boxed) { boxed.";
+            let completion_offset = patch(
+                &mut text,
+                "db.struct_data(self.id)",
+                &format!("{prefix} }}\ndb.struct_data(self.id)"),
+            ) + prefix.len();
+            (text, completion_offset)
+        };
+        let _it = stdx::timeit("apply_change");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        completion_offset
+    };
+    let position = FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "dyn alias completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        let items = items.map_or(0, |it| it.len());
+        report("dyn alias completion", format_args!("{:.2?} ({items} items)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_unused_var_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+
+    // Warm up the rest of the file so that only the new function is measured below.
+    host.analysis()
+        .diagnostics(&diagnostics_config, ide::AssistResolveStrategy::None, file_id)
+        .unwrap();
+
+    let lets = (0..100).map(|i| format!("    let unused_{i} = {i}u32;")).join("\n");
+    append_to_file(&mut host, file_id, &format!("\nfn unused_var_bench() {{\n{lets}\n}}\n"));
+
+    let db = host.raw_database();
+    let sema = hir::Semantics::new(db);
+    let func = sema
+        .parse(file_id)
+        .syntax()
+        .descendants()
+        .filter_map(ast::Fn::cast)
+        .find(|it| it.name().is_some_and(|name| name.text() == "unused_var_bench"))
+        .and_then(|it| sema.to_def(&it))
+        .unwrap();
+    let body = hir::DefWithBody::from(func).into();
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "type checking").entered();
+        let it = Instant::now();
+        db.infer(body);
+        report("type checking", format_args!("{:.2?}", it.elapsed()));
+    }
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "data-flow analysis").entered();
+        let it = Instant::now();
+        let _ = db.borrowck(body);
+        report("data-flow analysis", format_args!("{:.2?}", it.elapsed()));
+    }
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "diagnostics").entered();
+        let it = Instant::now();
+        let diagnostics = host
+            .analysis()
+            .diagnostics(&diagnostics_config, ide::AssistResolveStrategy::None, file_id)
+            .unwrap();
+        let unused = diagnostics.iter().filter(|it| it.code.as_str() == "unused_variables").count();
+        report("diagnostics", format_args!("{:.2?} ({unused} unused variables)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_document_highlight_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let text = host.analysis().file_text(file_id).unwrap();
+    let offset = text.find("pub struct Crate {").unwrap() + "pub struct ".len();
+    let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+    let config = HighlightRelatedConfig {
+        references: true,
+        exit_points: true,
+        break_points: true,
+        closure_captures: true,
+        yield_points: true,
+    };
+
+    for label in ["document highlight (cold)", "document highlight (warm)"] {
+        let _p = tracing::span!(tracing::Level::INFO, "document highlight", label).entered();
+        let it = Instant::now();
+        let highlights = host.analysis().highlight_related(config.clone(), position).unwrap();
+        let occurrences = highlights.map_or(0, |it| it.len());
+        report(label, format_args!("{:.2?} ({occurrences} occurrences)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_use_tree_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let snippets = [
+        ("use tree completion (shallow)", "\nuse std::$0;\n"),
+        ("use tree completion (nested)", "\nuse std::collections::$0;\n"),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+
+    // Complete the same path with use tree completions turned on and off, to see how much of the
+    // latency they account for.
+    let position = append_with_cursor(&mut host, file_id, "\nuse std::collections::$0;\n");
+    for (label, enabled) in
+        [("use tree completion (enabled)", true), ("use tree completion (disabled)", false)]
+    {
+        let config =
+            CompletionConfig { enable_completions_for_use_tree: enabled, ..completion_config() };
+        bench_completion(&host, &config, position, label);
+    }
+}
+
+#[test]
+fn integrated_impl_trait_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let completion_offset = {
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let prefix = "fn impl_trait_bench() -> impl Iterator<Item = (u32, String)> + Clone {\n\
+                          (0..10u32).map(|it| (it, it.to_string()))\n\
+                          }\n\
+                          impl_trait_bench().";
+            let completion_offset = patch(
+                &mut text,
+                "db.struct_data(self.id)",
+                &format!("{prefix};\ndb.struct_data(self.id)"),
+            ) + prefix.len();
+            (text, completion_offset)
+        };
+        let _it = stdx::timeit("apply_change");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        completion_offset
+    };
+    let position = FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "impl trait completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        let items = host.analysis().completions(&completion_config(), position, None).unwrap();
+        let items = items.map_or(0, |it| it.len());
+        report("impl trait completion", format_args!("{:.2?} ({items} items)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_expected_type_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let original = host.analysis().file_text(file_id).unwrap().to_string();
+
+    // The first completion also pays for inferring the surrounding function, so warm it up
+    // before comparing the two contexts.
+    let cases = [
+        ("warm-up completion", "let expected_type_bench = "),
+        ("expected type completion", "let expected_type_bench: Option<Crate> = "),
+        ("untyped completion", "let expected_type_bench = "),
+    ];
+    for (label, prefix) in cases {
+        let completion_offset = {
+            let mut text = original.clone();
+            let completion_offset = patch(
+                &mut text,
+                "db.struct_data(self.id)",
+                &format!("{prefix};\ndb.struct_data(self.id)"),
+            ) + prefix.len();
+            let mut change = ChangeWithProcMacros::new();
+            change.change_file(file_id, Some(text));
+            host.apply_change(change);
+            completion_offset
+        };
+        let position =
+            FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
+        bench_completion(&host, &completion_config(), position, label);
+        host.gc();
+    }
+}
+
+#[test]
+fn integrated_turbofish_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let snippets = [
+        (
+            "turbofish completion (`Vec`)",
+            "\nfn vec_turbofish() {\n    let _ = Vec::<$0>::new();\n}\n",
+        ),
+        (
+            "turbofish completion (`HashMap`)",
+            "\nfn hash_map_turbofish() {\n    \
+             let _ = std::collections::HashMap::<String, $0>::new();\n}\n",
+        ),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_completion_docs_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn completion_docs_bench(it: Vec<u32>) {\n    it.$0\n}\n",
+    );
+
+    let items = bench_completion(&host, &completion_config(), position, "completion");
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "completion docs").entered();
+        let it = Instant::now();
+        let docs = items
+            .into_iter()
+            .filter_map(|item| item.documentation)
+            .map(crate::lsp::to_proto::documentation)
+            .count();
+        report("completion docs", format_args!("{:.2?} ({docs} documented items)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_match_arm_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    append_to_file(&mut host, file_id, "\nenum SmallBenchEnum { A, B, C, D, E }\n");
+    let snippets = [
+        (
+            "match arm completion (5 variants)",
+            "\nfn small_match_arm(it: SmallBenchEnum) {\n    match it {\n        $0\n    }\n}\n",
+        ),
+        (
+            "match arm completion (`SyntaxKind`)",
+            "\nfn syntax_kind_match_arm(it: syntax::SyntaxKind) {\n    \
+             match it {\n        $0\n    }\n}\n",
+        ),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_assoc_const_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let (mut consts, mut methods) = (String::new(), String::new());
+    for i in 0..30 {
+        format_to!(consts, "    const CONST_{i}: u32;\n");
+        format_to!(methods, "    fn method_{i}();\n");
+    }
+    append_to_file(
+        &mut host,
+        file_id,
+        &format!(
+            "\ntrait ConstBenchTrait {{\n{consts}}}\ntrait MethodBenchTrait {{\n{methods}}}\n"
+        ),
+    );
+    let snippets = [
+        ("assoc const completion", "\nfn assoc_const_bench() {\n    ConstBenchTrait::$0\n}\n"),
+        ("assoc method completion", "\nfn assoc_method_bench() {\n    MethodBenchTrait::$0\n}\n"),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_completion_context_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    warm_up_completions(&mut host, file_id, &config);
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn completion_context_bench(db: &dyn HirDatabase) {\n    db.$0\n}\n",
+    );
+    // Only profile the context analysis, which is printed once its span closes, to tell it apart from
+    // the candidate generation making up the rest of the completion time.
+    let _g = crate::tracing::hprof::init(
+        "CompletionContext::expand_and_analyze|CompletionContext::expand|CompletionContext::analyze",
+    );
+    bench_completion(&host, &config, position, "completion");
+}
+
+#[test]
+fn integrated_unresolved_import_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let (mut unresolved, mut resolved) = (String::new(), String::new());
+    for i in 0..30 {
+        format_to!(unresolved, "    pub use non_existent::Item{i};\n");
+        format_to!(resolved, "    pub struct Item{i};\n");
+    }
+    append_to_file(
+        &mut host,
+        file_id,
+        &format!(
+            "\nmod unresolved_bench {{\n{unresolved}}}\nmod resolved_bench {{\n{resolved}}}\n"
+        ),
+    );
+    let snippets = [
+        ("resolved import completion", "\nuse crate::resolved_bench::{$0};\n"),
+        ("unresolved import completion", "\nuse crate::unresolved_bench::{$0};\n"),
+    ];
+    bench_completions(&mut host, file_id, &completion_config(), &snippets);
+
+    // Compare the error-recovery completions with them turned off.
+    host.gc();
+    let config = CompletionConfig {
+        enable_completions_for_unresolved_imports: false,
+        ..completion_config()
+    };
+    let position = append_with_cursor(&mut host, file_id, "\nuse crate::unresolved_bench::{$0};\n");
+    bench_completion(&host, &config, position, "unresolved import completion (disabled)");
+}
+
+#[test]
+fn integrated_lifetime_bound_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // A lone `'` doesn't lex as a lifetime yet, so complete with the first letter of the name typed.
+    let snippets = [
+        (
+            "lifetime bound completion (where clause)",
+            "\nfn where_lifetime_bound<'a, 'b, T>(_: &'a T, _: &'b T)\nwhere\n    T: 'a$0\n{\n}\n",
+        ),
+        (
+            "lifetime bound completion (generic param)",
+            "\nfn param_lifetime_bound<'a, 'b, T: 'a + 'b$0>(_: &'a T, _: &'b T) {}\n",
+        ),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_unrelated_edit_inference_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    append_to_file(
+        &mut host,
+        file_id,
+        "\nfn inference_isolation_bench() {\n    let value = 92u32;\n    \
+         let items = vec![value; 3];\n    let first = items.first().copied();\n}\n\
+         \nfn unrelated_edit_bench() {\n    let unrelated = 0u8;\n}\n",
+    );
+
+    let infer = |host: &AnalysisHost| {
+        let db = host.raw_database();
+        let sema = hir::Semantics::new(db);
+        let func = sema
+            .parse(file_id)
+            .syntax()
+            .descendants()
+            .filter_map(ast::Fn::cast)
+            .find(|it| it.name().is_some_and(|name| name.text() == "inference_isolation_bench"))
+            .and_then(|it| sema.to_def(&it))
+            .unwrap();
+        let it = Instant::now();
+        let result = db.infer(hir::DefWithBody::from(func).into());
+        (it.elapsed(), result)
+    };
+
+    let before = {
+        let _p = tracing::span!(tracing::Level::INFO, "initial type checking").entered();
+        let (elapsed, result) = infer(&host);
+        report("initial type checking", format_args!("{elapsed:.2?}"));
+        result
+    };
+
+    {
+        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+        patch(&mut text, "let unrelated = 0u8;", "let unrelated = 0u8 + 1;");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+    }
+
+    {
+        let _p =
+            tracing::span!(tracing::Level::INFO, "type checking after unrelated edit").entered();
+        let (elapsed, after) = infer(&host);
+        // Salsa hands out the memoized `Arc` unless the query was re-executed.
+        let recomputed = !Arc::ptr_eq(&before, &after);
+        report(
+            "type checking after unrelated edit",
+            format_args!("{elapsed:.2?} (recomputed: {recomputed})"),
+        );
+    }
+}
+
+#[test]
+fn integrated_import_map_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (host, _vfs, _file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", false);
+    let db = host.raw_database();
+    let crates = db.crate_graph().iter().collect::<Vec<_>>();
+
+    // Building the import map also computes the crate's `DefMap`, which is measured separately.
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "def maps").entered();
+        let it = Instant::now();
+        for &krate in &crates {
+            db.crate_def_map(krate);
+        }
+        report("def maps", format_args!("{:.2?} ({} crates)", it.elapsed(), crates.len()));
+    }
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "import maps").entered();
+        let it = Instant::now();
+        let entries = crates.iter().map(|&krate| db.import_map(krate).len()).sum::<usize>();
+        report("import maps", format_args!("{:.2?} ({entries} entries)", it.elapsed()));
+    }
+}
+
+#[test]
+fn integrated_iterator_adapter_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let snippets = [(
+        "iterator adapter completion",
+        "\nfn iterator_adapters() {\n    let words = vec![\"a b\", \"c\"];\n    words\n        \
+             .iter()\n        .map(|it| it.trim())\n        .filter(|it| !it.is_empty())\n        \
+             .flat_map(|it| it.split(' '))\n        .take(10)\n        \
+             .collect::<Vec<_>>()\n        .$0\n}\n",
+    )];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_user_snippet_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+    let snippet_config = CompletionConfig { snippets: user_snippets(), ..completion_config() };
+    report("user snippets", snippet_config.snippets.len());
+
+    warm_up_completions(&mut host, file_id, &config);
+    let snippets = [
+        ("postfix completion", &config, "\nfn postfix() {\n    let value = 92;\n    value.$0\n}\n"),
+        (
+            "postfix completion (user snippets)",
+            &snippet_config,
+            "\nfn postfix_snippets() {\n    let value = 92;\n    value.$0\n}\n",
+        ),
+        ("prefix completion", &config, "\nfn prefix() {\n    $0\n}\n"),
+        (
+            "prefix completion (user snippets)",
+            &snippet_config,
+            "\nfn prefix_snippets() {\n    $0\n}\n",
+        ),
+    ];
+    for (label, config, snippet) in snippets {
+        host.gc();
+        let position = append_with_cursor(&mut host, file_id, snippet);
+        bench_completion(&host, config, position, label);
+    }
+}
+
+#[test]
+fn integrated_generate_function_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = AssistConfig { allowed: Some(vec![AssistKind::Generate]), ..assist_config() };
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn generate_function_bench() {\n    let n: u32 = undefined_be$0nch_fn(92, \"a\");\n}\n",
+    );
+    let frange = FileRange { file_id, range: TextRange::empty(position.offset) };
+    let resolve = AssistResolveStrategy::Single(SingleResolve {
+        assist_id: "generate_function".to_owned(),
+        assist_kind: AssistKind::Generate,
+    });
+
+    // The first request after loading pays for a lot of shared work, so warm it up first.
+    for (label, resolve) in [
+        ("warm-up assists", AssistResolveStrategy::None),
+        ("generate function (unresolved)", AssistResolveStrategy::None),
+        ("generate function (resolved)", resolve),
+    ] {
+        let _p = tracing::span!(tracing::Level::INFO, "generate function", label).entered();
+        let it = Instant::now();
+        let assists = host
+            .analysis()
+            .assists_with_fixes(&config, &diagnostics_config, resolve, frange)
+            .unwrap();
+        let elapsed = it.elapsed();
+        let assist = assists
+            .iter()
+            .find(|it| it.id.0 == "generate_function")
+            .expect("no generate function assist");
+        let edits = assist.source_change.as_ref().map_or(0, |it| it.source_file_edits.len());
+        report(label, format_args!("{elapsed:.2?} ({edits} file edits)"));
+    }
+}
+
+#[test]
+fn integrated_lint_pass_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let base_config = DiagnosticsConfig::test_sample();
+
+    // Warm up the rest of the file so that only the re-run after each edit is measured below.
+    host.analysis().diagnostics(&base_config, AssistResolveStrategy::None, file_id).unwrap();
+
+    // rust-analyzer has no `wildcard_imports` diagnostic, but a braced glob exercises the closest
+    // one, `unused_braces`, alongside the other style lints.
+    for (i, style_lints) in [false, true].into_iter().enumerate() {
+        let label =
+            if style_lints { "lint pass (style lints)" } else { "lint pass (no style lints)" };
+        let config = DiagnosticsConfig { style_lints, ..base_config.clone() };
+        append_to_file(
+            &mut host,
+            file_id,
+            &format!(
+                "\nmod lint_pass_bench_{i} {{\n    use crate::{{db::*}};\n\n    \
+                 fn lint_pass_bench(db: &dyn HirDatabase) -> u32 {{\n        \
+                 let unused = db;\n        if true {{\n            return 1;\n        \
+                 }} else {{\n            return 2;\n        }}\n    }}\n}}\n"
+            ),
+        );
+        let _p = tracing::span!(tracing::Level::INFO, "lint pass", label).entered();
+        let it = Instant::now();
+        let diagnostics =
+            host.analysis().diagnostics(&config, AssistResolveStrategy::None, file_id).unwrap();
+        let elapsed = it.elapsed();
+        let lints = diagnostics
+            .iter()
+            .filter(|it| {
+                matches!(it.code, DiagnosticCode::RustcLint(_) | DiagnosticCode::Clippy(_))
+            })
+            .count();
+        report(label, format_args!("{elapsed:.2?} ({lints} lints)"));
+        host.gc();
+    }
+}
+
+#[test]
+fn integrated_completion_memory_benchmark() {
+    if std::env::var("RA_BENCH_MEMORY").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // Complete after every `self.` in the file, spread over the whole file, so that the requests
+    // touch a lot of different functions and receiver types.
+    let text = host.analysis().file_text(file_id).unwrap();
+    let offsets = text.match_indices("self.").map(|(it, _)| it + "self.".len()).collect::<Vec<_>>();
+    let step = offsets.len().div_ceil(300).max(1);
+    let positions = offsets
+        .into_iter()
+        .step_by(step)
+        .map(|offset| FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() })
+        .collect::<Vec<_>>();
+
+    // The warm-up goes to the end of the file, after all of the positions.
+    warm_up_completions(&mut host, file_id, &config);
+    let before = profile::memory_usage();
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "completion requests").entered();
+        let it = Instant::now();
+        let mut items = 0;
+        for &position in &positions {
+            let completions = host.analysis().completions(&config, position, None).unwrap();
+            items += completions.map_or(0, |it| it.len());
+        }
+        report(
+            "completion requests",
+            format_args!("{:.2?} ({} requests, {items} items)", it.elapsed(), positions.len()),
+        );
+    }
+    let after = profile::memory_usage();
+    report("memory before", before);
+    report("memory after", after);
+    report("memory delta", after - before);
+}
+
+#[test]
+fn integrated_const_generic_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+    append_to_file(
+        &mut host,
+        file_id,
+        "\nstruct ConstArray<T, const N: usize>([T; N]);\nconst CONST_ARRAY_LEN: usize = 4;\n",
+    );
+
+    let snippets = [
+        (
+            "const generic completion (type argument)",
+            "\nfn const_generic_type_arg() {\n    let _ = ConstArray::<$0>([]);\n}\n",
+        ),
+        (
+            "const generic completion (const argument)",
+            "\nfn const_generic_const_arg() {\n    let _ = ConstArray::<u8, $0>([0; 4]);\n}\n",
+        ),
+    ];
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_circular_reexport_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    warm_up_completions(&mut host, file_id, &config);
+
+    // A ring of modules, each re-exporting everything from the next one, so that every module of
+    // the ring can name the items of all the others.
+    let modules = 10;
+    let mut ring = String::new();
+    for i in 0..modules {
+        let next = (i + 1) % modules;
+        format_to!(
+            ring,
+            "\npub mod reexport_cycle_{i} {{\n    pub use super::reexport_cycle_{next}::*;\n"
+        );
+        for j in 0..20 {
+            format_to!(ring, "    pub struct CycleItem{i}_{j};\n");
+        }
+        ring.push_str("}\n");
+    }
+    append_to_file(&mut host, file_id, &ring);
+
+    let snippets = [
+        (
+            "qualified completion",
+            "\nfn circular_reexport_qualified() {\n    reexport_cycle_0::$0\n}\n",
+        ),
+        (
+            "glob imported completion",
+            "\nfn circular_reexport_glob() {\n    use reexport_cycle_0::*;\n    Cycle$0\n}\n",
+        ),
+    ];
+    for (label, snippet) in snippets {
+        host.gc();
+        let position = append_with_cursor(&mut host, file_id, snippet);
+        let items = bench_completion(&host, &config, position, label);
+        let cycle_items = items.iter().filter(|it| it.label.starts_with("CycleItem")).count();
+        assert_eq!(cycle_items, modules * 20, "not all items of the cycle were completed");
+    }
+}
+
+#[test]
+fn integrated_mass_rename_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir-ty/src/interner.rs", true);
+
+    let text = host.analysis().file_text(file_id).unwrap();
+    let offset = text.find("pub struct Interner;").unwrap() + "pub struct ".len();
+    let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+
+    let rename = |host: &AnalysisHost, label: &str| {
+        let _p = tracing::span!(tracing::Level::INFO, "mass rename", label).entered();
+        let it = Instant::now();
+        host.analysis().prepare_rename(position).unwrap().unwrap();
+        let source_change = host.analysis().rename(position, "RenamedInterner").unwrap().unwrap();
+        let elapsed = it.elapsed();
+        let files = source_change.source_file_edits.len();
+        let references: usize =
+            source_change.source_file_edits.values().map(|(edit, _)| edit.len()).sum();
+        report(label, format_args!("{elapsed:.2?} ({references} references in {files} files)"));
+        references
+    };
+
+    let cold = rename(&host, "mass rename (cold)");
+    assert!(cold >= 1000, "expected at least 1000 references to `Interner`, found {cold}");
+    rename(&host, "mass rename (warm)");
+
+    // Adding a reference invalidates the search results for the edited file only.
+    {
+        let _it = stdx::timeit("change");
+        append_to_file(&mut host, file_id, "\nfn mass_rename_bench(_: Interner) {}\n");
+    }
+    let incremental = rename(&host, "mass rename (after edit)");
+    assert_eq!(incremental, cold + 1);
+}
+
+#[test]
+fn integrated_proc_macro_edit_benchmark() {
+    if std::env::var("RUN_PROC_MACRO_BENCHES").is_err() {
+        return;
+    }
+
+    // A workspace-local derive used by every struct of the `app` crate, so that reloading it after
+    // a rebuild has to expand all of them again.
+    const MODULES: usize = 50;
+    const STRUCTS: usize = 10;
+    let mut files = vec![
+        (
+            "Cargo.toml".to_owned(),
+            "[workspace]\nmembers = [\"app\", \"macros\"]\nresolver = \"2\"\n".to_owned(),
+        ),
+        (
+            "macros/Cargo.toml".to_owned(),
+            "[package]\nname = \"macros\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [lib]\nproc-macro = true\n"
+                .to_owned(),
+        ),
+        (
+            "macros/src/lib.rs".to_owned(),
+            r#"use proc_macro::{TokenStream, TokenTree};
+
+#[proc_macro_derive(Describe)]
+pub fn derive_describe(item: TokenStream) -> TokenStream {
+    let mut tokens = item.into_iter();
+    let name = loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => {
+                break tokens.next().expect("expected a struct name")
+            }
+            Some(_) => {}
+            None => panic!("expected a struct"),
+        }
+    };
+    format!("impl {name} {{ pub fn describe(&self) -> &'static str {{ \"{name}\" }} }}")
+        .parse()
+        .unwrap()
+}
+"#
+            .to_owned(),
+        ),
+        (
+            "app/Cargo.toml".to_owned(),
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nmacros = { path = \"../macros\" }\n"
+                .to_owned(),
+        ),
+    ];
+    let mut lib = String::new();
+    for m in 0..MODULES {
+        format_to!(lib, "mod module_{m};\n");
+        let mut module = String::from("use macros::Describe;\n");
+        for s in 0..STRUCTS {
+            format_to!(
+                module,
+                "\n#[derive(Describe)]\npub struct Item{s} {{\n    pub value: u32,\n}}\n"
+            );
+        }
+        let calls = (0..STRUCTS).map(|s| format!("Item{s} {{ value: {s} }}.describe()")).join(", ");
+        format_to!(
+            module,
+            "\npub fn describe_all() -> Vec<&'static str> {{\n    vec![{calls}]\n}}\n"
+        );
+        files.push((format!("app/src/module_{m}.rs"), module));
+    }
+    files.push(("app/src/lib.rs".to_owned(), lib));
+    let (mut host, _vfs, file_id) = load_temp_workspace("ra-proc-macro-edit-bench", &files);
+    let macros_file_id = file_id("macros/src/lib.rs");
+    let users = (0..MODULES).map(|m| file_id(&format!("app/src/module_{m}.rs"))).collect_vec();
+
+    // Without the proc-macro server the derive fails to expand, and there is nothing to invalidate.
+    let derive = {
+        let text = host.analysis().file_text(users[0]).unwrap();
+        let offset = text.find("Describe)]").unwrap();
+        FilePosition { file_id: users[0], offset: TextSize::try_from(offset).unwrap() }
+    };
+    let expanded =
+        host.analysis().expand_macro(derive).unwrap().expect("`#[derive(Describe)]` didn't expand");
+    assert!(
+        expanded.expansion.contains("describe"),
+        "unexpected expansion: {}",
+        expanded.expansion
+    );
+
+    let config = DiagnosticsConfig::test_sample();
+    let reanalyze = |host: &AnalysisHost, label: &str| {
+        let _p = tracing::span!(tracing::Level::INFO, "proc macro edit", label).entered();
+        let it = Instant::now();
+        let diagnostics: usize = users
+            .iter()
+            .map(|&file_id| {
+                host.analysis().diagnostics(&config, AssistResolveStrategy::None, file_id).unwrap()
+            })
+            .map(|it| it.len())
+            .sum();
+        let elapsed = it.elapsed();
+        report(
+            label,
+            format_args!("{elapsed:.2?} ({} files, {diagnostics} diagnostics)", users.len()),
+        );
+    };
+    reanalyze(&host, "proc macro users (cold)");
+    reanalyze(&host, "proc macro users (warm)");
+
+    // Editing the proc-macro's source doesn't change its expansions until it is rebuilt...
+    {
+        let _it = stdx::timeit("change");
+        append_to_file(&mut host, macros_file_id, "\nfn unused_helper() {}\n");
+    }
+    reanalyze(&host, "proc macro users (after edit)");
+
+    // ...after which the proc-macros are reloaded, invalidating every expansion.
+    {
+        let _it = stdx::timeit("proc macro reload");
+        let proc_macros = (*host.raw_database().proc_macros()).clone();
+        let mut change = ChangeWithProcMacros::new();
+        change.set_proc_macros(proc_macros);
+        host.apply_change(change);
+    }
+    reanalyze(&host, "proc macro users (after reload)");
+}
+
+#[test]
+fn integrated_macro_rules_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // A macro with many arms, each binding a few metavariables inside repetitions.
+    let mut arms = String::new();
+    for i in 0..50 {
+        format_to!(
+            arms,
+            "    (arm{i} $($name{i}:ident = $value{i}:expr),* ; $tail{i}:tt) => {{\n        \
+             $(let $name{i} = $value{i};)*\n        $tail{i}\n    }};\n"
+        );
+    }
+
+    // Add the macro and a function next to it in one change, so that every completion below runs
+    // against the same state.
+    let mut snippet = format!(
+        "\nmacro_rules! macro_rules_bench {{\n{arms}    ($last:$0) => {{}};\n    \
+         ($($key:ident => $val:expr),*) => {{ [$($$0),*] }};\n}}\n\n\
+         fn macro_rules_bench_outside() {{\n    let _ = $0;\n}}\n"
+    );
+    let mut cursors = Vec::new();
+    while let Some(idx) = snippet.find("$0") {
+        snippet.replace_range(idx..idx + "$0".len(), "");
+        cursors.push(TextSize::try_from(idx).unwrap());
+    }
+    let start = append_to_file(&mut host, file_id, &snippet);
+    let [fragment_specifier, metavariable, outside] = cursors[..] else {
+        panic!("expected three cursors in the snippet");
+    };
+
+    // The warm-up goes to the end of the file, after the cursors.
+    warm_up_completions(&mut host, file_id, &config);
+    for (label, cursor) in [
+        ("macro_rules completion (fragment specifier)", fragment_specifier),
+        ("macro_rules completion (metavariable)", metavariable),
+        ("completion outside macro_rules", outside),
+    ] {
+        let position = FilePosition { file_id, offset: start + cursor };
+        bench_completion(&host, &config, position, label);
+    }
+}
+
+#[test]
+fn integrated_raw_pointer_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // Each receiver is set up in front of the body of `Struct::repr`, with the cursor after the
+    // dot. Dereferencing a reference outside of an `unsafe` block isolates the cost of the raw
+    // pointer and the `unsafe` context.
+    warm_up_completions(&mut host, file_id, &config);
+    let receivers = [
+        (
+            "reference completion (&)",
+            "let reference = &self;\n        let _ = (*reference).",
+            ";\n        ",
+        ),
+        (
+            "raw pointer completion (*const)",
+            "let const_ptr = std::ptr::null::<Struct>();\n        let _ = unsafe { (*const_ptr).",
+            " };\n        ",
+        ),
+        (
+            "raw pointer completion (*mut)",
+            "let mut_ptr = std::ptr::null_mut::<Struct>();\n        let _ = unsafe { (*mut_ptr).",
+            " };\n        ",
+        ),
+    ];
+    for (label, prefix, suffix) in receivers {
+        let (text, completion_offset) = {
+            let _it = stdx::timeit("text_patch");
+            let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+            let from = "db.struct_data(self.id).repr";
+            let completion_offset =
+                patch(&mut text, from, &format!("{prefix}{suffix}{from}")) + prefix.len();
+            (text, completion_offset)
+        };
+        {
+            let _it = stdx::timeit("apply_change");
+            let mut change = ChangeWithProcMacros::new();
+            change.change_file(file_id, Some(text));
+            host.apply_change(change);
+        }
+
+        let position =
+            FilePosition { file_id, offset: TextSize::try_from(completion_offset).unwrap() };
+        let items = bench_completion(&host, &config, position, label);
+        assert!(items.iter().any(|it| it.lookup() == "repr"), "`Struct::repr` wasn't completed");
+        host.gc();
+    }
+}
+
+#[test]
+fn integrated_fill_match_arms_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, vfs, file_id) = load_rust_analyzer("./crates/syntax/src/lib.rs", true);
+    let config = AssistConfig { allowed: Some(vec![AssistKind::QuickFix]), ..assist_config() };
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+
+    let position = append_with_cursor(
+        &mut host,
+        file_id,
+        "\nfn fill_match_arms_bench(kind: SyntaxKind) {\n    match kind {\n        $0\n    }\n}\n",
+    );
+    let frange = FileRange { file_id, range: TextRange::empty(position.offset) };
+    let resolve = || {
+        AssistResolveStrategy::Single(SingleResolve {
+            assist_id: "add_missing_match_arms".to_owned(),
+            assist_kind: AssistKind::QuickFix,
+        })
+    };
+
+    let fill_match_arms = |host: &AnalysisHost, label: &str, resolve: AssistResolveStrategy| {
+        let _p = tracing::span!(tracing::Level::INFO, "fill match arms", label).entered();
+        let it = Instant::now();
+        let assists = host
+            .analysis()
+            .assists_with_fixes(&config, &diagnostics_config, resolve, frange)
+            .unwrap();
+        let elapsed = it.elapsed();
+        let assist = assists
+            .iter()
+            .find(|it| it.id.0 == "add_missing_match_arms")
+            .expect("no fill match arms assist");
+        let arms: usize = assist.source_change.as_ref().map_or(0, |it| {
+            it.source_file_edits
+                .values()
+                .flat_map(|(edit, _)| edit.iter())
+                .map(|indel| indel.insert.matches("=>").count())
+                .sum()
+        });
+        report(label, format_args!("{elapsed:.2?} ({arms} arms)"));
+        arms
+    };
+
+    // The first request after loading pays for a lot of shared work, so warm it up first.
+    fill_match_arms(&host, "warm-up assists", AssistResolveStrategy::None);
+    fill_match_arms(&host, "fill match arms (unresolved)", AssistResolveStrategy::None);
+    let before = fill_match_arms(&host, "fill match arms (resolved)", resolve());
+
+    let syntax_kind_file_id = {
+        let file = project_root().join("crates/parser/src/syntax_kind/generated.rs");
+        let path = VfsPath::from(AbsPathBuf::assert_utf8(file));
+        vfs.file_id(&path).unwrap_or_else(|| panic!("can't find virtual file for {path}"))
+    };
+    let text = {
+        let _it = stdx::timeit("text_patch");
+        let mut text = host.analysis().file_text(syntax_kind_file_id).unwrap().to_string();
+        patch(&mut text, "    EOF,\n", "    EOF,\n    FILL_MATCH_ARMS_BENCH,\n");
+        text
+    };
+    {
+        let _it = stdx::timeit("apply_change");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(syntax_kind_file_id, Some(text));
+        host.apply_change(change);
+    }
+    let after = fill_match_arms(&host, "fill match arms (after adding a variant)", resolve());
+    assert_eq!(after, before + 1);
+}
+
+#[test]
+fn integrated_view_hir_mir_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir-ty/src/infer/expr.rs", true);
+
+    let text = host.analysis().file_text(file_id).unwrap();
+    let offset = text.find("fn infer_expr_inner(").unwrap() + "fn ".len();
+    let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+
+    let view_hir = |host: &AnalysisHost, label: &str| {
+        let _p = tracing::span!(tracing::Level::INFO, "view hir", label).entered();
+        let it = Instant::now();
+        let hir = host.analysis().view_hir(position).unwrap();
+        let elapsed = it.elapsed();
+        assert!(hir.starts_with("fn infer_expr_inner"), "unexpected HIR: {hir}");
+        report(label, format_args!("{elapsed:.2?} ({} lines)", hir.lines().count()));
+    };
+    let view_mir = |host: &AnalysisHost, label: &str| {
+        let _p = tracing::span!(tracing::Level::INFO, "view mir", label).entered();
+        let it = Instant::now();
+        let mir = host.analysis().view_mir(position).unwrap();
+        let elapsed = it.elapsed();
+        report(label, format_args!("{elapsed:.2?} ({} lines)", mir.lines().count()));
+    };
+
+    view_hir(&host, "view hir (cold)");
+    view_hir(&host, "view hir (warm)");
+    view_mir(&host, "view mir (cold)");
+    view_mir(&host, "view mir (warm)");
+
+    // An edit elsewhere in the file reparses it, so the body is lowered again.
+    {
+        let _it = stdx::timeit("change");
+        append_to_file(&mut host, file_id, "\nfn view_hir_mir_bench() {}\n");
+    }
+    view_hir(&host, "view hir (after edit)");
+    view_mir(&host, "view mir (after edit)");
+}
+
+#[test]
+fn integrated_std_type_hover_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let types = [
+        "std::collections::HashMap<u8, u8>",
+        "std::collections::HashSet<u8>",
+        "std::collections::BTreeMap<u8, u8>",
+        "std::collections::BTreeSet<u8>",
+        "std::collections::VecDeque<u8>",
+        "std::collections::BinaryHeap<u8>",
+        "std::collections::LinkedList<u8>",
+        "std::vec::Vec<u8>",
+        "std::string::String",
+        "std::boxed::Box<u8>",
+        "std::rc::Rc<u8>",
+        "std::sync::Arc<u8>",
+        "std::sync::Mutex<u8>",
+        "std::sync::RwLock<u8>",
+        "std::cell::RefCell<u8>",
+        "std::cell::Cell<u8>",
+        "std::option::Option<u8>",
+        "std::result::Result<u8, u8>",
+        "std::path::PathBuf",
+        "std::ffi::OsString",
+    ];
+    let params = types.map(|ty| format!("_: &{ty}")).join(", ");
+    let snippet = format!("\nfn std_type_hover_bench({params}) {{}}\n");
+    let start = append_to_file(&mut host, file_id, &snippet);
+    // Hover over the last path segment, which names the type itself.
+    let offset_of = |ty: &str| {
+        let path = ty.split('<').next().unwrap();
+        let name = path.rsplit("::").next().unwrap();
+        let idx = snippet.find(&format!("&{path}")).unwrap() + 1 + path.len() - name.len();
+        start + TextSize::of(&snippet[..idx])
+    };
+
+    let config = hover_config();
+    let analysis = host.analysis();
+    let hover_at = |offset: TextSize| {
+        let range = FileRange { file_id, range: TextRange::empty(offset) };
+        let it = Instant::now();
+        let hover = analysis.hover(&config, range).unwrap().expect("no hover");
+        (it.elapsed(), hover.info.markup.as_str().len())
+    };
+
+    // The first hover pays for loading the sysroot's item trees, which isn't specific to any type.
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "warm-up hover").entered();
+        let (elapsed, _) = hover_at(offset_of(types[0]));
+        report("warm-up hover", format_args!("{elapsed:.2?}"));
+    }
+
+    let mut slowest = None;
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "std type hover").entered();
+        for ty in types {
+            let (elapsed, len) = hover_at(offset_of(ty));
+            report(format_args!("hover `{ty}`"), format_args!("{elapsed:.2?} ({len} bytes)"));
+            if slowest.map_or(true, |(_, slowest, _)| elapsed > slowest) {
+                slowest = Some((ty, elapsed, len));
+            }
+        }
+    }
+    let (ty, elapsed, len) = slowest.unwrap();
+    report("slowest hover", format_args!("`{ty}` {elapsed:.2?} ({len} bytes)"));
+}
+
+#[test]
+fn integrated_where_clause_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    const PARAMS: usize = 64;
+    let params = (0..PARAMS).map(|i| format!("T{i}")).join(", ");
+    // The parameters stay the same for every function, only the number of bounds on them grows,
+    // so the differences come from the predicates the solver has to consider.
+    let function = |bounds: usize| {
+        let where_clause = (0..bounds)
+            .map(|i| {
+                let (ty, next) = (i % PARAMS, (i + 1) % PARAMS);
+                match i / PARAMS {
+                    0 => format!("    T{ty}: Iterator<Item = T{next}>,"),
+                    1 => format!("    T{ty}: Clone + std::fmt::Debug + PartialEq<T{next}>,"),
+                    _ => format!("    T{ty}: From<T{next}> + Into<Vec<T{next}>>,"),
+                }
+            })
+            .join("\n");
+        format!(
+            "\nfn where_clause_bench_{bounds}<{params}>(arg: T0)\nwhere\n{where_clause}\n{{\n    arg.$0\n}}\n"
+        )
+    };
+
+    let mut complete = |bounds: usize, label: &str| {
+        let position = append_with_cursor(&mut host, file_id, &function(bounds));
+        bench_completion(&host, &completion_config(), position, label);
+        host.gc();
+    };
+
+    // Warm up with all bounds, so that the methods of the bound traits are already collected.
+    complete(3 * PARAMS, "warm-up completion");
+    for bounds in [0, PARAMS, 2 * PARAMS, 3 * PARAMS] {
+        complete(bounds, &format!("where clause completion ({bounds} bounds)"));
+    }
+}
+
+#[test]
+fn integrated_extern_crate_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    let position = append_with_cursor(&mut host, file_id, "\nextern crate s$0\n");
+    let complete = |enabled: bool, label: &str| {
+        let config = CompletionConfig {
+            enable_completions_for_extern_crate: enabled,
+            enable_completions_for_use_aliases: true,
+            enable_completions_for_cfg_variants: false,
+            ..completion_config()
+        };
+        bench_completion(&host, &config, position, label)
+    };
+
+    complete(true, "warm-up completion");
+    let enabled = complete(true, "extern crate completion (enabled)");
+    assert!(!enabled.is_empty(), "no crates completed after `extern crate`");
+    complete(false, "extern crate completion (disabled)");
+}
+
+#[test]
+fn integrated_file_deletion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, vfs, _file_id) = load_rust_analyzer("./crates/hir-ty/src/utils.rs", true);
+
+    let file_id_of = |file: &str| {
+        let path = VfsPath::from(AbsPathBuf::assert_utf8(project_root().join(file)));
+        vfs.file_id(&path).unwrap_or_else(|| panic!("can't find virtual file for {path}"))
+    };
+    let deleted = file_id_of("crates/hir-ty/src/utils.rs");
+    // Files of `hir-ty` that import from the deleted module.
+    let dependents = [
+        "crates/hir-ty/src/lib.rs",
+        "crates/hir-ty/src/infer.rs",
+        "crates/hir-ty/src/lower.rs",
+        "crates/hir-ty/src/method_resolution.rs",
+        "crates/hir-ty/src/display.rs",
+    ]
+    .map(|file| (file, file_id_of(file)));
+
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+    let diagnostics = |host: &AnalysisHost, label: &str| {
+        let _p = tracing::span!(tracing::Level::INFO, "file deletion", label).entered();
+        let it = Instant::now();
+        let mut unresolved = 0;
+        for (file, file_id) in dependents {
+            let it = Instant::now();
+            let diagnostics = host
+                .analysis()
+                .diagnostics(&diagnostics_config, AssistResolveStrategy::None, file_id)
+                .unwrap();
+            let count = diagnostics
+                .iter()
+                .filter(|it| matches!(it.code, DiagnosticCode::RustcHardError("E0432" | "E0583")))
+                .count();
+            report(
+                format_args!("  {file}"),
+                format_args!("{:.2?} ({count} unresolved)", it.elapsed()),
+            );
+            unresolved += count;
+        }
+        report(label, format_args!("{:.2?} ({unresolved} unresolved)", it.elapsed()));
+        unresolved
+    };
+
+    diagnostics(&host, "diagnostics (warm-up)");
+    let before = diagnostics(&host, "diagnostics (before deletion)");
+
+    {
+        let _it = stdx::timeit("apply_change");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(deleted, None);
+        host.apply_change(change);
+    }
+    let after = diagnostics(&host, "diagnostics (after deletion)");
+    assert!(after > before, "deleting the module didn't break any imports");
+}
+
+#[test]
+fn integrated_trait_bound_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let snippets = [
+        (
+            "trait bound completion (where clause)",
+            "\nfn where_trait_bound<T>(_: T)\nwhere\n    T: $0\n{\n}\n",
+        ),
+        ("trait bound completion (generic param)", "\nfn param_trait_bound<T: $0>(_: T) {}\n"),
+        (
+            "trait bound completion (after `+`)",
+            "\nfn plus_trait_bound<T>(_: T)\nwhere\n    T: Clone + $0\n{\n}\n",
+        ),
+    ];
+    let completions = bench_completions(&mut host, file_id, &config, &snippets);
+    for ((label, _), items) in snippets.iter().zip(completions) {
+        let traits = items
+            .iter()
+            .filter(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Trait))
+            .count();
+        report(format_args!("{label} (traits)"), traits);
+    }
+}
+
+#[test]
+fn integrated_find_replace_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let needle = "db.struct_data(self.";
+    let complete = |host: &AnalysisHost, label: &str| {
+        let text = host.analysis().file_text(file_id).unwrap();
+        let offset = text.find(needle).unwrap() + needle.len();
+        let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+        bench_completion(host, &config, position, label);
+    };
+
+    complete(&host, "warm-up completion");
+    complete(&host, "completion (before replace)");
+
+    // Re-indent the whole file with tabs, which keeps its meaning but touches almost every line.
+    let (text, substitutions) = {
+        let _it = stdx::timeit("text_patch");
+        let text = host.analysis().file_text(file_id).unwrap();
+        (text.replace("\n    ", "\n\t"), text.matches("\n    ").count())
+    };
+    report("substitutions", substitutions);
+    {
+        let _it = stdx::timeit("apply_change");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+    }
+    complete(&host, "completion (after replace)");
+    complete(&host, "completion (after replace, warm)");
+}
+
+#[test]
+fn integrated_impl_trait_return_type_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    warm_up_completions(&mut host, file_id, &config);
+    host.gc();
+
+    // After `impl` only traits are completed, along with the modules, path keywords and macros
+    // that can lead to one.
+    let position =
+        append_with_cursor(&mut host, file_id, "\nfn impl_trait_return() -> impl $0 {\n    0\n}\n");
+    for label in ["`impl Trait` completion", "`impl Trait` completion (warm)"] {
+        let items = bench_completion(&host, &config, position, label);
+        let traits = items
+            .iter()
+            .filter(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Trait))
+            .count();
+        report(format_args!("{label} (traits)"), traits);
+        assert!(traits > 0, "no traits were completed after `-> impl`");
+    }
+}
+
+#[test]
+fn integrated_many_impls_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    const IMPLS: usize = 64;
+    const METHODS_PER_IMPL: usize = 2;
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // Both types have the same methods, spread over many trait impls for `ManyImpls` and in a
+    // single inherent impl for `OneImpl`, so the difference is the cost of enumerating the impls.
+    let mut fixture = String::from("\nstruct ManyImpls;\nstruct OneImpl;\nimpl OneImpl {\n");
+    for i in 0..IMPLS {
+        for m in 0..METHODS_PER_IMPL {
+            format_to!(fixture, "    fn method_{i}_{m}(&self) {{}}\n");
+        }
+    }
+    fixture.push_str("}\n");
+    for i in 0..IMPLS {
+        let methods =
+            (0..METHODS_PER_IMPL).map(|m| format!("fn method_{i}_{m}(&self) {{}}")).join(" ");
+        format_to!(fixture, "trait ManyImplsTrait{i} {{ {methods} }}\n");
+        format_to!(fixture, "impl ManyImplsTrait{i} for ManyImpls {{}}\n");
+    }
+    append_to_file(&mut host, file_id, &fixture);
+
+    let snippets = [
+        ("one impl completion", "\nfn one_impl_bench() {\n    OneImpl.$0\n}\n"),
+        ("many impls completion", "\nfn many_impls_bench() {\n    ManyImpls.$0\n}\n"),
+    ];
+    let completions = bench_completions(&mut host, file_id, &config, &snippets);
+    for ((label, _), items) in snippets.iter().zip(completions) {
+        let methods = items
+            .iter()
+            .filter(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Method))
+            .count();
+        report(format_args!("{label} (methods)"), methods);
+    }
+}
+
+#[test]
+fn integrated_parallel_analysis_throughput_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let completion_config = completion_config();
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+    let hover_config = hover_config();
+    let inlay_hints_config = inlay_hints_config();
+    let highlight_config = HighlightConfig {
+        strings: true,
+        punctuation: true,
+        specialize_punctuation: true,
+        operator: true,
+        specialize_operator: true,
+        inject_doc_comment: true,
+        macro_bang: true,
+        syntactic_name_ref_highlighting: false,
+    };
+    let highlight_related_config = HighlightRelatedConfig {
+        references: true,
+        exit_points: true,
+        break_points: true,
+        closure_captures: true,
+        yield_points: true,
+    };
+
+    let position_after = |host: &AnalysisHost, needle: &str| {
+        let text = host.analysis().file_text(file_id).unwrap();
+        let offset = text.find(needle).unwrap() + needle.len();
+        FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() }
+    };
+    let struct_position = position_after(&host, "pub struct ");
+    let method_position = position_after(&host, "db.struct_data(self.id");
+    let completion_position = position_after(&host, "db.struct_data(self.");
+
+    // Each task uses a different analysis API and returns the number of results it produced.
+    type Task<'a> = (&'static str, Box<dyn Fn(&Analysis) -> usize + Sync + 'a>);
+    let tasks: [Task<'_>; 8] = [
+        (
+            "highlight",
+            Box::new(|analysis| analysis.highlight(highlight_config, file_id).unwrap().len()),
+        ),
+        (
+            "diagnostics",
+            Box::new(|analysis| {
+                let resolve = AssistResolveStrategy::None;
+                analysis.diagnostics(&diagnostics_config, resolve, file_id).unwrap().len()
+            }),
+        ),
+        (
+            "inlay hints",
+            Box::new(|analysis| {
+                analysis.inlay_hints(&inlay_hints_config, file_id, None).unwrap().len()
+            }),
+        ),
+        (
+            "hover",
+            Box::new(|analysis| {
+                let range = FileRange { file_id, range: TextRange::empty(struct_position.offset) };
+                analysis.hover(&hover_config, range).unwrap().map_or(0, |_| 1)
+            }),
+        ),
+        (
+            "completions",
+            Box::new(|analysis| {
+                let items = analysis.completions(&completion_config, completion_position, None);
+                items.unwrap().map_or(0, |it| it.len())
+            }),
+        ),
+        (
+            "document highlight",
+            Box::new(|analysis| {
+                let highlights =
+                    analysis.highlight_related(highlight_related_config.clone(), struct_position);
+                highlights.unwrap().map_or(0, |it| it.len())
+            }),
+        ),
+        (
+            "goto definition",
+            Box::new(|analysis| {
+                analysis.goto_definition(method_position).unwrap().map_or(0, |it| it.info.len())
+            }),
+        ),
+        ("folding ranges", Box::new(|analysis| analysis.folding_ranges(file_id).unwrap().len())),
+    ];
+
+    // Both runs start from the same edit, so that neither reuses the results of the other.
+    let invalidate = |host: &mut AnalysisHost, name: &str| {
+        append_to_file(host, file_id, &format!("\nfn parallel_bench_{name}() {{}}\n"));
+    };
+    let run = |analysis: &Analysis, name: &str, task: &dyn Fn(&Analysis) -> usize| {
+        let it = Instant::now();
+        let results = task(analysis);
+        report(format_args!("  {name}"), format_args!("{:.2?} ({results} results)", it.elapsed()));
+    };
+
+    invalidate(&mut host, "sequential");
+    let sequential = {
+        let _p = tracing::span!(tracing::Level::INFO, "sequential analysis").entered();
+        let analysis = host.analysis();
+        let it = Instant::now();
+        for (name, task) in &tasks {
+            run(&analysis, name, task);
+        }
+        it.elapsed()
+    };
+    report("sequential", format_args!("{sequential:.2?}"));
+    host.gc();
+
+    invalidate(&mut host, "parallel");
+    let parallel = {
+        let _p = tracing::span!(tracing::Level::INFO, "parallel analysis").entered();
+        let snapshots = tasks.iter().map(|_| host.analysis()).collect_vec();
+        let it = Instant::now();
+        std::thread::scope(|scope| {
+            for ((name, task), analysis) in tasks.iter().zip(snapshots) {
+                scope.spawn(move || run(&analysis, name, task));
+            }
+        });
+        it.elapsed()
+    };
+    report("parallel", format_args!("{parallel:.2?}"));
+    report("speedup", format_args!("{:.2}x", sequential.as_secs_f64() / parallel.as_secs_f64()));
+}
+
+#[test]
+fn integrated_sysroot_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let workspace_to_load = project_root();
+    let file = "./crates/hir/src/lib.rs";
+    let config = completion_config();
+
+    // Without a sysroot nothing from std resolves, so both the candidates and the work to find
+    // them shrink.
+    for (sysroot_label, sysroot) in
+        [("sysroot", Some(project_model::RustLibSource::Discover)), ("no sysroot", None)]
+    {
+        let cargo_config = CargoConfig { sysroot, ..CargoConfig::default() };
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: true,
+            with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+            prefill_caches: true,
+            dry_run: false,
+        };
+        enable_load_backtraces();
+        let it = Instant::now();
+        let WorkspaceInfo { db, vfs, .. } = load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap();
+        report(
+            format_args!("workspace loading ({sysroot_label})"),
+            format_args!("{:.2?}", it.elapsed()),
+        );
+        let mut host = AnalysisHost::with_database(db);
+        report(format_args!("files ({sysroot_label})"), host.file_count());
+        let file_id = {
+            let path = VfsPath::from(AbsPathBuf::assert_utf8(workspace_to_load.join(file)));
+            vfs.file_id(&path).unwrap_or_else(|| panic!("can't find virtual file for {path}"))
+        };
+
+        let snippets = [
+            (
+                format!("path completion ({sysroot_label})"),
+                "\nfn sysroot_path_bench() {\n    $0\n}\n",
+            ),
+            (
+                format!("method completion ({sysroot_label})"),
+                "\nfn sysroot_method_bench(v: Vec<u32>) {\n    v.$0\n}\n",
+            ),
+        ];
+        bench_completions(&mut host, file_id, &config, &snippets);
+    }
+}
+
+#[test]
+fn integrated_where_clause_bound_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // A generic function in the style of the ones in `hir`, with several parameters that already
+    // carry bounds, so that the completed bound is one predicate among many.
+    let function = |name: &str, bounds: &str| {
+        format!(
+            "
+fn {name}<'db, T, U, I, F>(db: &'db dyn HirDatabase, items: I, f: F) -> Vec<U>
+where
+    I: IntoIterator<Item = &'db T>,
+    F: Fn(&'db dyn HirDatabase, &T) -> Option<U>,
+    U: Clone + std::fmt::Debug,
+    T: {bounds}
+{{
+    items.into_iter().filter_map(|it| f(db, it)).collect()
+}}
+"
+        )
+    };
+
+    let snippets = [
+        ("where clause bound completion (`T: `)", function("where_bound_fresh", "$0")),
+        (
+            "where clause bound completion (`T: Clone + `)",
+            function("where_bound_added", "Clone + $0"),
+        ),
+    ];
+    let completions = bench_completions(&mut host, file_id, &config, &snippets);
+    for ((label, _), items) in snippets.iter().zip(completions) {
+        let traits = items
+            .iter()
+            .filter(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Trait))
+            .count();
+        report(format_args!("{label} (traits)"), traits);
+        assert!(traits > 0, "no traits completed in the where clause");
+    }
+}
+
+#[test]
+fn integrated_variant_construction_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+
+    // Construct a `ModuleDef` inside `Some(`, where every variant is rendered with its field.
+    let position = {
+        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+        let offset = patch(
+            &mut text,
+            "db.struct_data(self.id).name.clone()",
+            "let _ = Some(ModuleDef::);\ndb.struct_data(self.id).name.clone()",
+        ) + "let _ = Some(ModuleDef::".len();
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() }
+    };
+
+    let config = completion_config();
+    let complete = |label: &str| {
+        let items = bench_completion(&host, &config, position, label);
+        let variants: Vec<_> = items
+            .iter()
+            .filter(|it| it.kind == CompletionItemKind::SymbolKind(SymbolKind::Variant))
+            .map(|it| it.lookup().to_owned())
+            .collect();
+        report(format_args!("{label} (variants)"), variants.len());
+        variants
+    };
+
+    complete("warm-up completion");
+    let variants = {
+        let _span = profile::cpu_span();
+        complete("variant construction completion")
+    };
+    assert!(
+        variants.iter().any(|it| it == "Function()"),
+        "`ModuleDef::Function` wasn't completed: {variants:?}"
+    );
+}
+
+#[test]
+fn integrated_extract_function_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config =
+        AssistConfig { allowed: Some(vec![AssistKind::RefactorExtract]), ..assist_config() };
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+
+    // A long function whose statements each depend on the one before, so that extracting the
+    // middle ones needs a parameter and a return value.
+    const STATEMENTS: usize = 60;
+    const SELECTED: std::ops::Range<usize> = 20..40;
+    let mut function = String::from("\nfn extract_function_bench(v0: u64) -> u64 {\n");
+    let mut selection = (0, 0);
+    for i in 1..STATEMENTS {
+        if i == SELECTED.start {
+            selection.0 = function.len() + "    ".len();
+        }
+        format_to!(function, "    let v{i} = v{}.wrapping_mul(31).wrapping_add({i});\n", i - 1);
+        if i == SELECTED.end - 1 {
+            selection.1 = function.len() - "\n".len();
+        }
+    }
+    format_to!(function, "    v{}\n}}\n", STATEMENTS - 1);
+    let start = append_to_file(&mut host, file_id, &function);
+    let range = TextRange::new(
+        start + TextSize::try_from(selection.0).unwrap(),
+        start + TextSize::try_from(selection.1).unwrap(),
+    );
+    let frange = FileRange { file_id, range };
+
+    // The first request after loading pays for a lot of shared work, so warm it up first.
+    for (label, resolve) in [
+        ("warm-up assists", AssistResolveStrategy::None),
+        ("extract function (unresolved)", AssistResolveStrategy::None),
+        ("extract function (resolved)", AssistResolveStrategy::All),
+    ] {
+        let _p = tracing::span!(tracing::Level::INFO, "extract function", label).entered();
+        let it = Instant::now();
+        let assists = host
+            .analysis()
+            .assists_with_fixes(&config, &diagnostics_config, resolve, frange)
+            .unwrap();
+        let elapsed = it.elapsed();
+        let assist = assists
+            .iter()
+            .find(|it| it.id.0 == "extract_function")
+            .expect("no extract function assist");
+        let edits = assist.source_change.as_ref().map_or(0, |it| it.source_file_edits.len());
+        report(
+            label,
+            format_args!("{elapsed:.2?} ({} assists, {edits} file edits)", assists.len()),
+        );
+    }
+}
+
+#[test]
+fn integrated_nested_use_path_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // The second item of a braced group goes through the use tree list completion path, which
+    // resolves the shared prefix again, so compare it with completing the plain path.
+    let snippets = [
+        ("single use path completion", "\nuse std::collections::$0;\n"),
+        ("nested use path completion", "\nuse std::collections::{HashMap, $0};\n"),
+        ("deeply nested use path completion", "\nuse std::{collections::{HashMap, $0}, fmt};\n"),
+    ];
+    let completions = bench_completions(&mut host, file_id, &config, &snippets);
+    for ((_, snippet), items) in snippets.iter().zip(completions) {
+        assert!(
+            items.iter().any(|it| it.label == "HashSet"),
+            "`HashSet` wasn't completed for {snippet:?}"
+        );
+    }
+}
+
+#[test]
+fn integrated_watched_file_edit_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let diagnostics_config = DiagnosticsConfig::test_sample();
+    append_to_file(
+        &mut host,
+        file_id,
+        "\nfn watched_file_bench() -> u32 {\n    let value = 0u32;\n    value\n}\n",
+    );
+
+    // Like the file watcher of the server, the callback only records that the file changed, and
+    // the file is analyzed again once the change has been applied.
+    let changes = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    host.watch_file(file_id, {
+        let changes = changes.clone();
+        move || {
+            changes.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    });
+    host.analysis()
+        .diagnostics(&diagnostics_config, ide::AssistResolveStrategy::None, file_id)
+        .unwrap();
+
+    const EDITS: usize = 50;
+    let mut latencies = Vec::with_capacity(EDITS);
+    let mut reanalyzed = 0;
+    let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+    for i in 0..EDITS {
+        patch(&mut text, &format!("let value = {i}u32;"), &format!("let value = {}u32;", i + 1));
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text.clone()));
+
+        let _p = tracing::span!(tracing::Level::INFO, "watched file edit").entered();
+        let it = Instant::now();
+        host.apply_change(change);
+        if changes.swap(0, std::sync::atomic::Ordering::SeqCst) > 0 {
+            host.analysis()
+                .diagnostics(&diagnostics_config, ide::AssistResolveStrategy::None, file_id)
+                .unwrap();
+            reanalyzed += 1;
+        }
+        latencies.push(it.elapsed());
+    }
+    assert_eq!(reanalyzed, EDITS, "the watcher wasn't called for every edit");
+
+    let total: Duration = latencies.iter().sum();
+    let max = latencies.iter().max().unwrap();
+    report(
+        "watched file edit to diagnostics",
+        format_args!("{:.2?} average, {max:.2?} max ({EDITS} edits)", total / EDITS as u32),
+    );
+}
+
+#[test]
+fn integrated_struct_update_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    let mut fields = String::new();
+    for i in 0..20 {
+        format_to!(fields, "    f{i}: u32,\n");
+    }
+    append_to_file(
+        &mut host,
+        file_id,
+        &format!("\n#[derive(Default)]\nstruct StructUpdateBench {{\n{fields}}}\n"),
+    );
+
+    // After `..` the base expression is completed, which is a different context from the field
+    // list that is completed without it.
+    let cases = [("field completion", "f1", "$0"), ("struct update completion", "base", "..$0")];
+    let snippets = cases
+        .iter()
+        .enumerate()
+        .map(|(i, (label, _, rest))| {
+            let snippet = format!(
+                "\nfn struct_update_bench_{i}(base: StructUpdateBench) {{\n    \
+                 let _ = StructUpdateBench {{ f0: 1, {rest} }};\n}}\n"
+            );
+            (label, snippet)
+        })
+        .collect::<Vec<_>>();
+    let completions = bench_completions(&mut host, file_id, &config, &snippets);
+    for ((_, expected, _), items) in cases.iter().zip(completions) {
+        assert!(items.iter().any(|it| it.label == *expected), "`{expected}` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_query_profile_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    append_to_file(
+        &mut host,
+        file_id,
+        "\nfn query_profile_bench() -> u32 {\n    let value = 92u32;\n    value + 1\n}\n",
+    );
+    let body = |host: &AnalysisHost| {
+        let sema = hir::Semantics::new(host.raw_database());
+        let func = sema
+            .parse(file_id)
+            .syntax()
+            .descendants()
+            .filter_map(ast::Fn::cast)
+            .find(|it| it.name().is_some_and(|name| name.text() == "query_profile_bench"))
+            .and_then(|it| sema.to_def(&it))
+            .unwrap();
+        hir::DefWithBody::from(func)
+    };
+    let profile = |host: &AnalysisHost, label: &str, expect_memo_hit: bool| {
+        let _p = tracing::span!(tracing::Level::INFO, "query profile", label).entered();
+        let profile = host.profile_query(|db| hir::db::MirBodyQuery.in_db(db), body(host).into());
+        assert_eq!(profile.memo_hit, expect_memo_hit, "unexpected memo hit for {label}");
+        report(
+            label,
+            format_args!(
+                "{:.2?} (memo hit: {}, {} dependencies executed)",
+                profile.wall_time, profile.memo_hit, profile.executed_dependencies
+            ),
+        );
+    };
+
+    profile(&host, "MIR body (cold)", false);
+    profile(&host, "MIR body (memoized)", true);
+
+    let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+    patch(&mut text, "let value = 92u32;", "let value = 93u32;");
+    let mut change = ChangeWithProcMacros::new();
+    change.change_file(file_id, Some(text));
+    host.apply_change(change);
+    profile(&host, "MIR body (after editing the body)", false);
+}
+
+#[test]
+fn integrated_deref_coercion_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // `Struct` has a lot of inherent methods, which are only found through `Deref` for the smart
+    // pointers.
+    let receivers = [
+        ("direct completion", "Struct"),
+        ("deref completion (Arc)", "std::sync::Arc<Struct>"),
+        ("deref completion (Box)", "Box<Struct>"),
+        ("deref completion (Rc)", "std::rc::Rc<Struct>"),
+    ];
+    let snippets = receivers
+        .iter()
+        .enumerate()
+        .map(|(i, (label, ty))| {
+            (label, format!("\nfn deref_coercion_bench_{i}(value: {ty}) {{\n    value.$0\n}}\n"))
+        })
+        .collect::<Vec<_>>();
+    for items in bench_completions(&mut host, file_id, &config, &snippets) {
+        assert!(items.iter().any(|it| it.lookup() == "repr"), "`Struct::repr` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_hkt_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // The type of a higher-ranked bound is resolved with the `for<'a>` lifetime in scope, compare
+    // it against the same bound without the binder.
+    let bounds = [
+        ("plain bound completion", "Fn(&$0) -> usize"),
+        ("higher-ranked bound completion", "for<'a> Fn(&'a $0) -> usize"),
+        (
+            "nested higher-ranked bound completion",
+            "for<'a> Fn(&'a dyn for<'b> Fn(&'b $0)) -> usize",
+        ),
+    ];
+    let snippets = bounds
+        .iter()
+        .enumerate()
+        .map(|(i, (label, bound))| {
+            (label, format!("\nfn hkt_bench_{i}<F>(_f: F)\nwhere\n    F: {bound},\n{{\n}}\n"))
+        })
+        .collect::<Vec<_>>();
+    for items in bench_completions(&mut host, file_id, &config, &snippets) {
+        assert!(items.iter().any(|it| it.lookup() == "Struct"), "`Struct` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_never_type_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // Every expression coerces to `!`, so compare it against an ordinary expected type.
+    let cases = [
+        ("expected u32 completion", "u32", "return $0"),
+        ("expected never completion", "!", "return $0"),
+        ("diverging let completion", "!", "let _: ! = $0;"),
+    ];
+    let snippets = cases
+        .iter()
+        .enumerate()
+        .map(|(i, (label, ret, body))| {
+            (label, format!("\nfn never_type_bench_{i}() -> {ret} {{\n    {body}\n}}\n"))
+        })
+        .collect::<Vec<_>>();
+    for items in bench_completions(&mut host, file_id, &config, &snippets) {
+        assert!(items.iter().any(|it| it.lookup() == "loop"), "`loop` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_apply_change_tracing_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", false);
+
+    // Record everything `ide` logs, down to `TRACE`, while the edits are applied.
+    let events = EventCollector::default();
+    let filter = Targets::new().with_target("ide", tracing::Level::TRACE);
+    let subscriber = Registry::default().with(events.clone().with_filter(filter));
+    let _g = tracing::subscriber::set_default(subscriber);
+
+    const EDITS: usize = 50;
+    let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+    let it = Instant::now();
+    for i in 0..EDITS {
+        format_to!(text, "\nfn apply_change_tracing_bench_{i}() {{}}\n");
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text.clone()));
+        host.apply_change(change);
+    }
+    let elapsed = it.elapsed();
+    report("apply_change with TRACE logging", format_args!("{elapsed:.2?} ({EDITS} edits)"));
+
+    let messages = events.0.lock().unwrap();
+    let count = |message: &str| messages.iter().filter(|it| *it == message).count();
+    assert_eq!(count("apply_change: 1 files changed"), EDITS);
+    assert_eq!(count("apply_change: invalidating None"), EDITS);
+}
+
+#[test]
+fn integrated_type_ascription_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // The `expr: Type` syntax is gone from the language, nightly only keeps type ascription as
+    // the unstable `type_ascribe!` macro. Measure it next to the ascription of a `let`, which is
+    // what rust-analyzer completes as a type ascription context. rust-analyzer doesn't expand
+    // `type_ascribe!`, so there is nothing to complete in it yet.
+    let cases = [
+        ("let ascription completion", "let value: $0 = 92u32;"),
+        ("type_ascribe! completion", "let value = core::prelude::v1::type_ascribe!(92u32, $0);"),
+    ];
+    let snippets = cases
+        .iter()
+        .enumerate()
+        .map(|(i, (label, body))| {
+            (label, format!("\nfn type_ascription_bench_{i}() {{\n    {body}\n}}\n"))
+        })
+        .collect::<Vec<_>>();
+    bench_completions(&mut host, file_id, &config, &snippets);
+}
+
+#[test]
+fn integrated_completion_ranking_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let (mut host, _vfs, file_id) = load_rust_analyzer("./crates/hir/src/lib.rs", true);
+    let config = completion_config();
+
+    // A path into a generated module completes to all of its items, which shows how sorting them by
+    // relevance scales next to generating them.
+    warm_up_completions(&mut host, file_id, &config);
+    let cases = [("100 items", 100), ("500 items", 500), ("2000 items", 2000)];
+    for (i, (label, item_count)) in cases.into_iter().enumerate() {
+        let fns = (0..item_count).map(|it| format!("    pub fn ranked_{it}() -> u32 {{ {it} }}\n"));
+        let snippet = format!(
+            "\nmod ranking_bench_{i} {{\n{}}}\n\
+             fn ranking_bench_{i}() -> u32 {{\n    ranking_bench_{i}::$0\n}}\n",
+            fns.format("")
+        );
+        let position = append_with_cursor(&mut host, file_id, &snippet);
+
+        let (items, generation) = {
+            let _p =
+                tracing::span!(tracing::Level::INFO, "completion item generation", label).entered();
+            let _span = profile::cpu_span();
+            let it = Instant::now();
+            let items = host.analysis().completions(&config, position, None).unwrap().unwrap();
+            (items, it.elapsed())
+        };
+        assert!(items.len() >= item_count, "only {} items were completed", items.len());
+
+        // The relevance of each item is computed while rendering it, so it is part of the generation.
+        // This only sorts the items by their score, the way the LSP layer does before sending them.
+        let sorting = {
+            let _p = tracing::span!(tracing::Level::INFO, "completion sorting", label).entered();
+            let _span = profile::cpu_span();
+            let it = Instant::now();
+            let mut ranked = items.iter().collect::<Vec<_>>();
+            ranked.sort_by_key(|it| cmp::Reverse(it.relevance.score()));
+            it.elapsed()
+        };
+        report(
+            label,
+            format_args!(
+                "generation {generation:.2?}, sorting {sorting:.2?} ({} items)",
+                items.len()
+            ),
+        );
+        host.gc();
+    }
+}
+
+#[test]
+fn integrated_sysroot_loading_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let workspace_to_load = project_root();
+    let load_cargo_config = LoadCargoConfig {
+        load_out_dirs_from_check: true,
+        with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+        prefill_caches: false,
+        dry_run: false,
+    };
+
+    let mut timings = Vec::new();
+    enable_load_backtraces();
+    for (label, sysroot) in [
+        ("workspace loading (with sysroot)", Some(project_model::RustLibSource::Discover)),
+        ("workspace loading (without sysroot)", None),
+    ] {
+        let cargo_config = CargoConfig { sysroot, ..CargoConfig::default() };
+        let _p = tracing::span!(tracing::Level::INFO, "workspace loading", label).entered();
+        let it = Instant::now();
+        let WorkspaceInfo { db, .. } = load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap();
+        let elapsed = it.elapsed();
+        let files = AnalysisHost::with_database(db).file_count();
+        report(label, format_args!("{elapsed:.2?} ({files} files)"));
+        timings.push(elapsed);
+    }
+    let [with_sysroot, without_sysroot] = timings[..] else { unreachable!() };
+    report(
+        "sysroot loading overhead",
+        format_args!("{:.2?}", with_sysroot.saturating_sub(without_sysroot)),
+    );
+}
+
+#[test]
+fn integrated_async_main_completion_benchmark() {
+    if std::env::var("RUN_PROC_MACRO_BENCHES").is_err() {
+        return;
+    }
+
+    // rust-analyzer doesn't depend on tokio, so write a small workspace with a look-alike
+    // `#[tokio::main]` which, like the real one, moves the body of `async fn main` into an async
+    // block passed to the runtime.
+    let files = [
+        (
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"app\", \"tokio\", \"tokio-macros\"]\nresolver = \"2\"\n",
+        ),
+        (
+            "tokio-macros/Cargo.toml",
+            "[package]\nname = \"tokio-macros\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [lib]\nproc-macro = true\n",
+        ),
+        (
+            "tokio-macros/src/lib.rs",
+            r#"use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+#[proc_macro_attribute]
+pub fn main(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut tokens = item
+        .into_iter()
+        .filter(|it| !matches!(it, TokenTree::Ident(ident) if ident.to_string() == "async"))
+        .collect::<Vec<_>>();
+    let Some(TokenTree::Group(body)) = tokens.pop() else { panic!("expected a function body") };
+    let mut future: TokenStream = "async move".parse().unwrap();
+    future.extend([TokenTree::Group(body)]);
+    let mut block_on: TokenStream = "::tokio::block_on".parse().unwrap();
+    block_on.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, future))]);
+    tokens.push(TokenTree::Group(Group::new(Delimiter::Brace, block_on)));
+    tokens.into_iter().collect()
+}
+"#,
+        ),
+        (
+            "tokio/Cargo.toml",
+            "[package]\nname = \"tokio\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\ntokio-macros = { path = \"../tokio-macros\" }\n",
+        ),
+        (
+            "tokio/src/lib.rs",
+            "pub use tokio_macros::main;\n\n\
+             pub fn block_on<F: std::future::Future>(_future: F) -> F::Output {\n    \
+             unimplemented!()\n}\n",
+        ),
+        (
+            "app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\ntokio = { path = \"../tokio\" }\n",
+        ),
+        (
+            "app/src/main.rs",
+            "#[tokio::main]\nasync fn main() {\n    \
+             let greeting = String::from(\"hello\");\n    let _ = greeting.len();\n}\n",
+        ),
+    ];
+    let (mut host, _vfs, file_ids) = load_temp_workspace("ra-async-main-bench", &files);
+    let file_id = file_ids("app/src/main.rs");
+
+    // Without the proc-macro server the attribute fails to expand and the body is analyzed as
+    // written, so make sure completion really goes through the transformed one.
+    let attr = FilePosition { file_id, offset: TextSize::from("#[tokio::".len() as u32) };
+    let expanded =
+        host.analysis().expand_macro(attr).unwrap().expect("`#[tokio::main]` didn't expand");
+    assert!(
+        expanded.expansion.contains("block_on"),
+        "unexpected expansion: {}",
+        expanded.expansion
+    );
+
+    let position = {
+        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+        let offset = patch(&mut text, "greeting.len()", "greeting.") + "greeting.".len();
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() }
+    };
+
+    {
+        let _p = tracing::span!(tracing::Level::INFO, "async main completion").entered();
+        let _span = profile::cpu_span();
+        let it = Instant::now();
+        let items =
+            host.analysis().completions(&completion_config(), position, None).unwrap().unwrap();
+        report(
+            "async main completion",
+            format_args!("{:.2?} ({} items)", it.elapsed(), items.len()),
+        );
+        assert!(items.iter().any(|it| it.lookup() == "len"), "`String::len` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_async_trait_completion_benchmark() {
+    if std::env::var("RUN_PROC_MACRO_BENCHES").is_err() {
+        return;
+    }
+
+    // A look-alike `#[async_trait]` which, like the real one, turns every `async fn` of the trait
+    // or impl into a `fn` returning `Pin<Box<dyn Future>>`, with the body moved into an async
+    // block.
+    let files = [
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"async-trait\"]\nresolver = \"2\"\n"),
+        (
+            "async-trait/Cargo.toml",
+            "[package]\nname = \"async-trait\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [lib]\nproc-macro = true\n",
+        ),
+        (
+            "async-trait/src/lib.rs",
+            r#"use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+#[proc_macro_attribute]
+pub fn async_trait(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut tokens = item.into_iter().collect::<Vec<_>>();
+    let Some(TokenTree::Group(body)) = tokens.pop() else { panic!("expected a trait or impl body") };
+    let mut items = Vec::new();
+    let mut body = body.stream().into_iter();
+    while let Some(token) = body.next() {
+        if !matches!(&token, TokenTree::Ident(ident) if ident.to_string() == "async") {
+            items.push(token);
+            continue;
+        }
+        let mut output = Vec::new();
+        let mut in_output = false;
+        let fn_body = loop {
+            match body.next().expect("unterminated async fn") {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break Some(group),
+                TokenTree::Punct(punct) if punct.as_char() == ';' => break None,
+                TokenTree::Punct(punct) if punct.as_char() == '-' && !in_output => {
+                    body.next();
+                    in_output = true;
+                }
+                token if in_output => output.push(token),
+                token => items.push(token),
+            }
+        };
+        let output: TokenStream =
+            if output.is_empty() { "()".parse().unwrap() } else { output.into_iter().collect() };
+        let output =
+            format!("-> ::core::pin::Pin<Box<dyn ::core::future::Future<Output = {output}> + Send + '_>>");
+        items.extend(output.parse::<TokenStream>().unwrap());
+        match fn_body {
+            Some(fn_body) => {
+                let mut future: TokenStream = "async move".parse().unwrap();
+                future.extend([TokenTree::Group(fn_body)]);
+                let mut boxed: TokenStream = "Box::pin".parse().unwrap();
+                boxed.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, future))]);
+                items.push(TokenTree::Group(Group::new(Delimiter::Brace, boxed)));
+            }
+            None => items.extend(";".parse::<TokenStream>().unwrap()),
+        }
+    }
+    tokens.push(TokenTree::Group(Group::new(Delimiter::Brace, items.into_iter().collect())));
+    tokens.into_iter().collect()
+}
+"#,
+        ),
+        (
+            "app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nasync-trait = { path = \"../async-trait\" }\n",
+        ),
+        (
+            "app/src/lib.rs",
+            r#"pub struct Store;
+
+#[async_trait::async_trait]
+pub trait Load {
+    async fn load(&self, key: String) -> String;
+}
+
+pub trait LoadSync {
+    fn load_sync(&self, key: String) -> String;
+}
+
+#[async_trait::async_trait]
+impl Load for Store {
+    async fn load(&self, key: String) -> String {
+        let greeting = format!("hello {key}");
+        let _ = greeting.len();
+        greeting
+    }
+}
+
+impl LoadSync for Store {
+    fn load_sync(&self, key: String) -> String {
+        let greeting = format!("hello {key}");
+        let _ = greeting.is_empty();
+        greeting
+    }
+}
+"#,
+        ),
+    ];
+    let (mut host, _vfs, file_ids) = load_temp_workspace("ra-async-trait-bench", &files);
+    let file_id = file_ids("app/src/lib.rs");
+
+    // Without the proc-macro server the attribute fails to expand and the `async fn` bodies are
+    // analyzed as written, so make sure completion really goes through the desugared ones.
+    let text = host.analysis().file_text(file_id).unwrap().to_string();
+    let attr = text.find("#[async_trait::async_trait]\nimpl").unwrap() + "#[async_trait::".len();
+    let attr = FilePosition { file_id, offset: TextSize::try_from(attr).unwrap() };
+    let expanded =
+        host.analysis().expand_macro(attr).unwrap().expect("`#[async_trait]` didn't expand");
+    assert!(
+        expanded.expansion.contains("Box::pin"),
+        "unexpected expansion: {}",
+        expanded.expansion
+    );
+
+    warm_up_completions(&mut host, file_id, &completion_config());
+    let cases = [
+        ("async trait impl completion", "greeting.len()"),
+        ("sync trait impl completion", "greeting.is_empty()"),
+    ];
+    for (label, call) in cases {
+        let mut text = text.clone();
+        let offset = patch(&mut text, call, "greeting.") + "greeting.".len();
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+        let items = bench_completion(&host, &completion_config(), position, label);
+        assert!(items.iter().any(|it| it.lookup() == "len"), "`String::len` wasn't completed");
+    }
+}
+
+#[test]
+fn integrated_macro_module_completion_benchmark() {
+    if std::env::var("RUN_PROC_MACRO_BENCHES").is_err() {
+        return;
+    }
+
+    // An attribute that re-emits the module it is applied to with one more item, so that the
+    // module's scope only exists in the expansion and the cursor has to be mapped into it.
+    let files = [
+        ("Cargo.toml", "[workspace]\nmembers = [\"app\", \"module-macros\"]\nresolver = \"2\"\n"),
+        (
+            "module-macros/Cargo.toml",
+            "[package]\nname = \"module-macros\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [lib]\nproc-macro = true\n",
+        ),
+        (
+            "module-macros/src/lib.rs",
+            r#"use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+#[proc_macro_attribute]
+pub fn extend(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut tokens = item.into_iter().collect::<Vec<_>>();
+    let Some(TokenTree::Group(body)) = tokens.pop() else { panic!("expected a module body") };
+    let mut items = body.stream();
+    items.extend("pub fn generated_perimeter() -> u32 { 0 }".parse::<TokenStream>().unwrap());
+    tokens.push(TokenTree::Group(Group::new(Delimiter::Brace, items)));
+    tokens.into_iter().collect()
+}
+"#,
+        ),
+        (
+            "app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nmodule-macros = { path = \"../module-macros\" }\n",
+        ),
+        (
+            "app/src/lib.rs",
+            "#[module_macros::extend]\nmod shapes {\n    pub fn area() -> u32 {\n        \
+             let radius = 2;\n        radius * radius\n    }\n}\n",
+        ),
+    ];
+    let (mut host, _vfs, file_ids) = load_temp_workspace("ra-macro-module-bench", &files);
+    let file_id = file_ids("app/src/lib.rs");
+
+    // Without the proc-macro server the attribute fails to expand and the module is analyzed as
+    // written, without the generated item.
+    let attr = FilePosition { file_id, offset: TextSize::from("#[module_macros::".len() as u32) };
+    let expanded = host
+        .analysis()
+        .expand_macro(attr)
+        .unwrap()
+        .expect("`#[module_macros::extend]` didn't expand");
+    assert!(
+        expanded.expansion.contains("generated_perimeter"),
+        "unexpected expansion: {}",
+        expanded.expansion
+    );
+
+    let position = {
+        let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+        let offset = patch(&mut text, "radius * radius", "radius * gen") + "radius * gen".len();
+        let mut change = ChangeWithProcMacros::new();
+        change.change_file(file_id, Some(text));
+        host.apply_change(change);
+        FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() }
+    };
+
+    for label in ["macro module completion (cold)", "macro module completion (warm)"] {
+        let items = bench_completion(&host, &completion_config(), position, label);
+        // The local comes from the user's tokens and the function only from the expansion, so both
+        // resolving means the cursor was mapped into the generated module.
+        for name in ["radius", "generated_perimeter"] {
+            assert!(items.iter().any(|it| it.lookup() == name), "`{name}` wasn't completed");
+        }
+    }
+}
+
+#[test]
+fn integrated_proc_macro_error_benchmark() {
+    if std::env::var("RUN_PROC_MACRO_BENCHES").is_err() {
+        return;
+    }
+
+    // An attribute that rejects every function whose name starts with `bad_`, half of them.
+    const FUNCTIONS: usize = 100;
+    let mut files = vec![
+        (
+            "Cargo.toml".to_owned(),
+            "[workspace]\nmembers = [\"app\", \"checks\"]\nresolver = \"2\"\n".to_owned(),
+        ),
+        (
+            "checks/Cargo.toml".to_owned(),
+            "[package]\nname = \"checks\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [lib]\nproc-macro = true\n"
+                .to_owned(),
+        ),
+        (
+            "checks/src/lib.rs".to_owned(),
+            r##"use proc_macro::{TokenStream, TokenTree};
+
+#[proc_macro_attribute]
+pub fn checked(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let is_bad = item.clone().into_iter().any(
+        |it| matches!(it, TokenTree::Ident(ident) if ident.to_string().starts_with("bad_")),
+    );
+    if is_bad {
+        return r#"compile_error!("functions can't be bad");"#.parse().unwrap();
+    }
+    item
+}
+"##
+            .to_owned(),
+        ),
+        (
+            "app/Cargo.toml".to_owned(),
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nchecks = { path = \"../checks\" }\n"
+                .to_owned(),
+        ),
+    ];
+    let mut lib = String::new();
+    for f in 0..FUNCTIONS {
+        let name = if f % 2 == 0 { format!("good_{f}") } else { format!("bad_{f}") };
+        format_to!(lib, "#[checks::checked]\npub fn {name}() -> u32 {{\n    {f}\n}}\n\n");
+    }
+    files.push(("app/src/lib.rs".to_owned(), lib));
+    let (host, _vfs, file_ids) = load_temp_workspace("ra-proc-macro-error-bench", &files);
+    let file_id = file_ids("app/src/lib.rs");
+
+    let config = DiagnosticsConfig::test_sample();
+    for label in ["proc macro error diagnostics (cold)", "proc macro error diagnostics (warm)"] {
+        let _p =
+            tracing::span!(tracing::Level::INFO, "proc macro error diagnostics", label).entered();
+        let it = Instant::now();
+        let diagnostics =
+            host.analysis().diagnostics(&config, AssistResolveStrategy::None, file_id).unwrap();
+        let elapsed = it.elapsed();
+        let errors = diagnostics
+            .iter()
+            .filter(|it| it.code == DiagnosticCode::Ra("macro-error", Severity::Error))
+            .count();
+        report(
+            label,
+            format_args!(
+                "{elapsed:.2?} ({} diagnostics, {errors} macro errors)",
+                diagnostics.len()
+            ),
+        );
+        // Without the proc-macro server none of the attributes expand, so none of them fail either.
+        assert_eq!(errors, FUNCTIONS / 2, "unexpected diagnostics: {diagnostics:#?}");
+    }
+}
+
+#[test]
+fn integrated_large_vfs_completion_benchmark() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    // The same completion in a small and a huge crate of trivial modules, without a sysroot, so
+    // that the difference comes from the number of files rather than from what is in them.
+    for modules in [16, 4096] {
+        let mut files = vec![
+            (
+                "Cargo.toml".to_owned(),
+                "[package]\nname = \"large-vfs\"\nversion = \"0.0.0\"\nedition = \"2021\"\n"
+                    .to_owned(),
+            ),
+            (
+                "src/entry.rs".to_owned(),
+                "pub fn entry(value: u32) -> u32 {\n    val\n}\n".to_owned(),
+            ),
+        ];
+        let mut lib = String::from("pub mod entry;\n");
+        for i in 0..modules {
+            format_to!(lib, "pub mod m{i};\n");
+            files.push((
+                format!("src/m{i}.rs"),
+                format!(
+                    "pub struct Item{i};\n\npub fn item_{i}() -> Item{i} {{\n    Item{i}\n}}\n"
+                ),
+            ));
+        }
+        files.push(("src/lib.rs".to_owned(), lib));
+        let cargo_config = CargoConfig::default();
+        let load_cargo_config = LoadCargoConfig {
+            load_out_dirs_from_check: false,
+            with_proc_macro_server: ProcMacroServerChoice::None,
+            prefill_caches: true,
+            dry_run: false,
+        };
+        let (host, _vfs, file_ids) = load_temp_workspace_with(
+            &format!("ra-large-vfs-bench-{modules}"),
+            &files,
+            &cargo_config,
+            &load_cargo_config,
+        );
+        report(format_args!("files ({modules} modules)"), host.file_count());
+        let file_id = file_ids("src/entry.rs");
+        let offset =
+            host.analysis().file_text(file_id).unwrap().rfind("val").unwrap() + "val".len();
+        let position = FilePosition { file_id, offset: TextSize::try_from(offset).unwrap() };
+
+        for run in ["first", "second"] {
+            let label = format!("{run} completion ({modules} modules)");
+            let items = bench_completion(&host, &completion_config(), position, &label);
+            assert!(items.iter().any(|it| it.lookup() == "value"), "`value` wasn't completed");
+        }
+    }
+}
+
+/// The outcome of a benchmark run by [`run_all_benchmarks`].
+#[derive(Debug)]
+pub(crate) struct BenchmarkResult {
+    pub(crate) name: &'static str,
+    /// Wall-clock time of the whole benchmark, including loading the workspace.
+    pub(crate) elapsed: Duration,
+    /// The labels and values the benchmark passed to `report`, in order. Empty if the benchmark
+    /// was skipped because its `RUN_*_BENCHES` variable isn't set.
+    pub(crate) measurements: Vec<(String, String)>,
+    /// The panic message, if the benchmark failed.
+    pub(crate) failure: Option<String>,
+}
+
+/// Every benchmark of this module, in the order they are defined in. New benchmarks have to be
+/// added here to be picked up by [`run_all_benchmarks`].
+const BENCHMARKS: &[(&str, fn())] = &[
+    ("integrated_highlighting_benchmark", integrated_highlighting_benchmark),
+    ("integrated_completion_benchmark", integrated_completion_benchmark),
+    ("integrated_diagnostics_benchmark", integrated_diagnostics_benchmark),
+    ("integrated_builtin_type_hover_benchmark", integrated_builtin_type_hover_benchmark),
+    (
+        "integrated_common_prefix_completion_benchmark",
+        integrated_common_prefix_completion_benchmark,
+    ),
+    ("integrated_return_type_completion_benchmark", integrated_return_type_completion_benchmark),
+    (
+        "integrated_branch_switch_completion_benchmark",
+        integrated_branch_switch_completion_benchmark,
+    ),
+    (
+        "integrated_invalidated_file_highlighting_benchmark",
+        integrated_invalidated_file_highlighting_benchmark,
+    ),
+    ("integrated_parsing_throughput_benchmark", integrated_parsing_throughput_benchmark),
+    (
+        "integrated_symbol_prefix_vs_substring_benchmark",
+        integrated_symbol_prefix_vs_substring_benchmark,
+    ),
+    ("integrated_inlay_hints_type_change_benchmark", integrated_inlay_hints_type_change_benchmark),
+    ("integrated_pattern_completion_benchmark", integrated_pattern_completion_benchmark),
+    ("integrated_impl_body_completion_benchmark", integrated_impl_body_completion_benchmark),
+    ("integrated_generic_env_completion_benchmark", integrated_generic_env_completion_benchmark),
+    ("integrated_dyn_alias_completion_benchmark", integrated_dyn_alias_completion_benchmark),
+    ("integrated_unused_var_benchmark", integrated_unused_var_benchmark),
+    ("integrated_document_highlight_benchmark", integrated_document_highlight_benchmark),
+    ("integrated_use_tree_completion_benchmark", integrated_use_tree_completion_benchmark),
+    ("integrated_impl_trait_completion_benchmark", integrated_impl_trait_completion_benchmark),
+    (
+        "integrated_expected_type_completion_benchmark",
+        integrated_expected_type_completion_benchmark,
+    ),
+    ("integrated_turbofish_completion_benchmark", integrated_turbofish_completion_benchmark),
+    ("integrated_completion_docs_benchmark", integrated_completion_docs_benchmark),
+    ("integrated_match_arm_completion_benchmark", integrated_match_arm_completion_benchmark),
+    ("integrated_assoc_const_completion_benchmark", integrated_assoc_const_completion_benchmark),
+    ("integrated_completion_context_benchmark", integrated_completion_context_benchmark),
+    (
+        "integrated_unresolved_import_completion_benchmark",
+        integrated_unresolved_import_completion_benchmark,
+    ),
+    (
+        "integrated_lifetime_bound_completion_benchmark",
+        integrated_lifetime_bound_completion_benchmark,
+    ),
+    (
+        "integrated_unrelated_edit_inference_benchmark",
+        integrated_unrelated_edit_inference_benchmark,
+    ),
+    ("integrated_import_map_benchmark", integrated_import_map_benchmark),
+    (
+        "integrated_iterator_adapter_completion_benchmark",
+        integrated_iterator_adapter_completion_benchmark,
+    ),
+    ("integrated_user_snippet_completion_benchmark", integrated_user_snippet_completion_benchmark),
+    ("integrated_generate_function_benchmark", integrated_generate_function_benchmark),
+    ("integrated_lint_pass_benchmark", integrated_lint_pass_benchmark),
+    ("integrated_completion_memory_benchmark", integrated_completion_memory_benchmark),
+    (
+        "integrated_const_generic_completion_benchmark",
+        integrated_const_generic_completion_benchmark,
+    ),
+    (
+        "integrated_circular_reexport_completion_benchmark",
+        integrated_circular_reexport_completion_benchmark,
+    ),
+    ("integrated_mass_rename_benchmark", integrated_mass_rename_benchmark),
+    ("integrated_proc_macro_edit_benchmark", integrated_proc_macro_edit_benchmark),
+    ("integrated_macro_rules_completion_benchmark", integrated_macro_rules_completion_benchmark),
+    ("integrated_raw_pointer_completion_benchmark", integrated_raw_pointer_completion_benchmark),
+    ("integrated_fill_match_arms_benchmark", integrated_fill_match_arms_benchmark),
+    ("integrated_view_hir_mir_benchmark", integrated_view_hir_mir_benchmark),
+    ("integrated_std_type_hover_benchmark", integrated_std_type_hover_benchmark),
+    ("integrated_where_clause_completion_benchmark", integrated_where_clause_completion_benchmark),
+    ("integrated_extern_crate_completion_benchmark", integrated_extern_crate_completion_benchmark),
+    ("integrated_file_deletion_benchmark", integrated_file_deletion_benchmark),
+    ("integrated_trait_bound_completion_benchmark", integrated_trait_bound_completion_benchmark),
+    ("integrated_find_replace_completion_benchmark", integrated_find_replace_completion_benchmark),
+    (
+        "integrated_impl_trait_return_type_completion_benchmark",
+        integrated_impl_trait_return_type_completion_benchmark,
+    ),
+    ("integrated_many_impls_completion_benchmark", integrated_many_impls_completion_benchmark),
+    (
+        "integrated_parallel_analysis_throughput_benchmark",
+        integrated_parallel_analysis_throughput_benchmark,
+    ),
+    ("integrated_sysroot_completion_benchmark", integrated_sysroot_completion_benchmark),
+    (
+        "integrated_where_clause_bound_completion_benchmark",
+        integrated_where_clause_bound_completion_benchmark,
+    ),
+    (
+        "integrated_variant_construction_completion_benchmark",
+        integrated_variant_construction_completion_benchmark,
+    ),
+    ("integrated_extract_function_benchmark", integrated_extract_function_benchmark),
+    (
+        "integrated_nested_use_path_completion_benchmark",
+        integrated_nested_use_path_completion_benchmark,
+    ),
+    ("integrated_watched_file_edit_benchmark", integrated_watched_file_edit_benchmark),
+    (
+        "integrated_struct_update_completion_benchmark",
+        integrated_struct_update_completion_benchmark,
+    ),
+    ("integrated_query_profile_benchmark", integrated_query_profile_benchmark),
+    (
+        "integrated_deref_coercion_completion_benchmark",
+        integrated_deref_coercion_completion_benchmark,
+    ),
+    ("integrated_hkt_completion_benchmark", integrated_hkt_completion_benchmark),
+    ("integrated_never_type_completion_benchmark", integrated_never_type_completion_benchmark),
+    ("integrated_apply_change_tracing_benchmark", integrated_apply_change_tracing_benchmark),
+    (
+        "integrated_type_ascription_completion_benchmark",
+        integrated_type_ascription_completion_benchmark,
+    ),
+    ("integrated_completion_ranking_benchmark", integrated_completion_ranking_benchmark),
+    ("integrated_sysroot_loading_benchmark", integrated_sysroot_loading_benchmark),
+    ("integrated_async_main_completion_benchmark", integrated_async_main_completion_benchmark),
+    ("integrated_async_trait_completion_benchmark", integrated_async_trait_completion_benchmark),
+    ("integrated_macro_module_completion_benchmark", integrated_macro_module_completion_benchmark),
+    ("integrated_proc_macro_error_benchmark", integrated_proc_macro_error_benchmark),
+    ("integrated_large_vfs_completion_benchmark", integrated_large_vfs_completion_benchmark),
+];
+
+thread_local! {
+    /// Where `report` collects measurements while [`run_all_benchmarks`] runs a benchmark.
+    static MEASUREMENTS: RefCell<Option<Vec<(String, String)>>> = const { RefCell::new(None) };
+}
+
+/// Runs every benchmark one after another, without the test runner, and returns their results.
+///
+/// The benchmarks are still gated by their environment variables, so set `RUN_SLOW_BENCHES` (and
+/// `RUN_PROC_MACRO_BENCHES`) for them to do any work. A failing benchmark doesn't stop the run.
+pub(crate) fn run_all_benchmarks() -> Vec<BenchmarkResult> {
+    BENCHMARKS
+        .iter()
+        .map(|&(name, benchmark)| {
+            MEASUREMENTS.with(|it| *it.borrow_mut() = Some(Vec::new()));
+            let it = Instant::now();
+            let outcome = std::panic::catch_unwind(benchmark);
+            let elapsed = it.elapsed();
+            let measurements = MEASUREMENTS.with(|it| it.borrow_mut().take()).unwrap_or_default();
+            let failure = outcome.err().map(|payload| {
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|it| it.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "non-string panic payload".to_owned())
+            });
+            BenchmarkResult { name, elapsed, measurements, failure }
+        })
+        .collect()
+}
+
+#[test]
+fn run_all_integrated_benchmarks() {
+    if std::env::var("RUN_ALL_BENCHES").is_err() {
+        return;
+    }
+
+    let results = run_all_benchmarks();
+    for result in &results {
+        let status = match (&result.failure, result.measurements.is_empty()) {
+            (Some(failure), _) => format!("failed: {failure}"),
+            (None, true) => "skipped".to_owned(),
+            (None, false) => format!("{} measurements", result.measurements.len()),
+        };
+        report(result.name, format_args!("{:.2?} ({status})", result.elapsed));
+    }
+    let failed = results.iter().filter(|it| it.failure.is_some()).count();
+    assert_eq!(failed, 0, "{failed} of {} benchmarks failed", results.len());
+}
+
+/// Loads rust-analyzer itself, returning the host together with the vfs and
+/// the id of `file`.
+fn load_rust_analyzer(file: &str, prefill_caches: bool) -> (AnalysisHost, Vfs, FileId) {
+    let workspace_to_load = project_root();
+
+    let cargo_config = CargoConfig {
+        sysroot: Some(project_model::RustLibSource::Discover),
+        ..CargoConfig::default()
+    };
+    let load_cargo_config = LoadCargoConfig {
+        load_out_dirs_from_check: true,
+        with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+        prefill_caches,
+        dry_run: false,
+    };
+
+    enable_load_backtraces();
+    let WorkspaceInfo { db, vfs, .. } = {
+        let _it = stdx::timeit("workspace loading");
+        load_workspace_info_at(
+            &workspace_to_load,
+            &cargo_config,
+            &load_cargo_config,
+            &report_load_warnings,
+        )
+        .unwrap()
+    };
+    let host = AnalysisHost::with_database(db);
+    report("files", host.file_count());
+
+    let file_id = {
+        let file = workspace_to_load.join(file);
+        let path = VfsPath::from(AbsPathBuf::assert_utf8(file));
+        vfs.file_id(&path).unwrap_or_else(|| panic!("can't find virtual file for {path}"))
+    };
+    (host, vfs, file_id)
+}
+
+/// Writes `files` into a fresh `name` directory in the temporary directory and
+/// loads it as a Cargo workspace, with proc-macros and build scripts. Returns
+/// the host together with the vfs and a lookup of the ids of the written files
+/// by their relative path.
+fn load_temp_workspace<P: AsRef<str>, T: AsRef<str>>(
+    name: &str,
+    files: &[(P, T)],
+) -> (AnalysisHost, Vfs, impl Fn(&str) -> FileId) {
+    let cargo_config = CargoConfig {
+        sysroot: Some(project_model::RustLibSource::Discover),
+        ..CargoConfig::default()
+    };
+    let load_cargo_config = LoadCargoConfig {
+        load_out_dirs_from_check: true,
+        with_proc_macro_server: ProcMacroServerChoice::Sysroot,
+        prefill_caches: true,
+        dry_run: false,
+    };
+    load_temp_workspace_with(name, files, &cargo_config, &load_cargo_config)
+}
+
+/// Like [`load_temp_workspace`], but loads the workspace with the given configs.
+fn load_temp_workspace_with<P: AsRef<str>, T: AsRef<str>>(
+    name: &str,
+    files: &[(P, T)],
+    cargo_config: &CargoConfig,
+    load_cargo_config: &LoadCargoConfig,
+) -> (AnalysisHost, Vfs, impl Fn(&str) -> FileId) {
+    let workspace_to_load = std::env::temp_dir().join(name);
+    // Files left over from a previous run, which may have written different ones, would be
+    // loaded as well.
+    match std::fs::remove_dir_all(&workspace_to_load) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => {
+            panic!("can't clear {}: {err}", workspace_to_load.display())
+        }
+        _ => (),
+    }
+    for (path, text) in files {
+        let path = workspace_to_load.join(path.as_ref());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text.as_ref()).unwrap();
+    }
+
+    enable_load_backtraces();
+    let it = Instant::now();
+    let WorkspaceInfo { db, vfs, .. } = load_workspace_info_at(
+        &workspace_to_load,
+        cargo_config,
+        load_cargo_config,
+        &report_load_warnings,
+    )
+    .unwrap();
+    report(format_args!("workspace loading ({name})"), format_args!("{:.2?}", it.elapsed()));
+    let host = AnalysisHost::with_database(db);
+
+    let file_ids: FxHashMap<String, FileId> = files
+        .iter()
+        .filter_map(|(path, _)| {
+            let path = path.as_ref();
+            let vfs_path = VfsPath::from(AbsPathBuf::assert_utf8(workspace_to_load.join(path)));
+            Some((path.to_owned(), vfs.file_id(&vfs_path)?))
+        })
+        .collect();
+    let file_id = move |path: &str| {
+        file_ids.get(path).copied().unwrap_or_else(|| panic!("can't find virtual file for {path}"))
+    };
+    (host, vfs, file_id)
+}
+
+/// Appends `snippet` to the end of the file, returning the offset it starts at.
+fn append_to_file(host: &mut AnalysisHost, file_id: FileId, snippet: &str) -> TextSize {
+    let mut text = host.analysis().file_text(file_id).unwrap().to_string();
+    let offset = TextSize::of(&*text);
+    text.push_str(snippet);
+    let mut change = ChangeWithProcMacros::new();
+    change.change_file(file_id, Some(text));
+    let changed_at = Instant::now();
+    host.apply_change(change);
+    // Snapshots taken from here on must see the new text.
+    assert!(host.last_change_timestamp().is_some_and(|it| it >= changed_at));
+    offset
+}
+
+/// Appends `snippet` to the end of the file, returning the position of the
+/// `$0` cursor marker in it.
+fn append_with_cursor(host: &mut AnalysisHost, file_id: FileId, snippet: &str) -> FilePosition {
+    let cursor = snippet.find("$0").expect("no cursor marker in snippet");
+    let snippet = snippet.replacen("$0", "", 1);
+    let offset = append_to_file(host, file_id, &snippet) + TextSize::of(&snippet[..cursor]);
+    FilePosition { file_id, offset }
+}
+
+/// Times the completion at `position`, reporting it under `label`.
+fn bench_completion(
+    host: &AnalysisHost,
+    config: &CompletionConfig,
+    position: FilePosition,
+    label: &str,
+) -> Vec<CompletionItem> {
+    let _p = tracing::span!(tracing::Level::INFO, "completion", label).entered();
+    let it = Instant::now();
+    let items = host.analysis().completions(config, position, None).unwrap().unwrap_or_default();
+    report(label, format_args!("{:.2?} ({} items)", it.elapsed(), items.len()));
+    items
+}
+
+/// The first completions after loading pay for a lot of shared work, so benchmarks comparing
+/// completions run a path and a dot completion first to keep that work out of the comparison.
+fn warm_up_completions(host: &mut AnalysisHost, file_id: FileId, config: &CompletionConfig) {
+    for (label, snippet) in [
+        ("warm-up completion (path)", "\nfn path_completion_warm_up() {\n    $0\n}\n"),
+        ("warm-up completion (dot)", "\nfn dot_completion_warm_up(it: u32) {\n    it.$0\n}\n"),
+    ] {
+        let position = append_with_cursor(host, file_id, snippet);
+        bench_completion(host, config, position, label);
+    }
+}
+
+/// Warms up, then appends each snippet to the end of the file in turn and times the completion at
+/// its `$0` cursor marker. Returns the completed items of each snippet.
+fn bench_completions<L: AsRef<str>, S: AsRef<str>>(
+    host: &mut AnalysisHost,
+    file_id: FileId,
+    config: &CompletionConfig,
+    snippets: &[(L, S)],
+) -> Vec<Vec<CompletionItem>> {
+    warm_up_completions(host, file_id, config);
+    snippets
+        .iter()
+        .map(|(label, snippet)| {
+            host.gc();
+            let position = append_with_cursor(host, file_id, snippet.as_ref());
+            bench_completion(host, config, position, label.as_ref())
+        })
+        .collect()
+}
+
+fn completion_config() -> CompletionConfig {
+    CompletionConfig {
+        enable_postfix_completions: true,
+        enable_imports_on_the_fly: true,
+        enable_self_on_the_fly: true,
+        enable_private_editable: true,
+        enable_completions_for_pattern_bindings: true,
+        enable_completions_for_use_tree: true,
+        enable_completions_for_unresolved_imports: true,
+        enable_completions_for_extern_crate: true,
+        enable_completions_for_use_aliases: true,
+        enable_completions_for_cfg_variants: false,
+        include_deprecated: true,
+        enable_term_search: true,
+        term_search_fuel: 200,
+        max_type_inference_depth: 32,
+        min_word_length_for_fuzzy: 3,
+        full_function_signatures: false,
+        callable: Some(CallableSnippets::FillArguments),
+        enable_auto_brace: true,
+        snippet_cap: SnippetCap::new(true),
+        insert_use: InsertUseConfig {
+            granularity: ImportGranularity::Crate,
+            prefix_kind: hir::PrefixKind::ByCrate,
+            enforce_granularity: true,
+            group: true,
+            skip_glob_imports: true,
+        },
+        prefer_no_std: false,
+        prefer_prelude: true,
+        snippets: Vec::new(),
+        limit: None,
+        preselect_first_item: true,
+    }
+}
+
+fn assist_config() -> AssistConfig {
+    AssistConfig {
+        snippet_cap: SnippetCap::new(true),
+        allowed: None,
+        insert_use: InsertUseConfig {
+            granularity: ImportGranularity::Crate,
+            prefix_kind: hir::PrefixKind::ByCrate,
+            enforce_granularity: true,
+            group: true,
+            skip_glob_imports: true,
+        },
+        prefer_no_std: false,
+        prefer_prelude: true,
+        assist_emit_must_use: false,
+        term_search_fuel: 400,
+    }
+}
+
+/// A set of custom snippets like users have configured, including the ones rust-analyzer ships with
+/// by default.
+fn user_snippets() -> Vec<Snippet> {
+    let snippet = |prefix: &[&str], postfix: &[&str], body: &str, requires: &[&str], scope| {
+        let strings = |it: &[&str]| it.iter().map(|&it| it.to_owned()).collect::<Vec<_>>();
+        Snippet::new(
+            &strings(prefix),
+            &strings(postfix),
+            &[body.to_owned()],
+            "user snippet",
+            &strings(requires),
+            scope,
+        )
+        .unwrap()
+    };
+    vec![
+        snippet(&[], &["arc"], "Arc::new(${receiver})", &["std::sync::Arc"], SnippetScope::Expr),
+        snippet(&[], &["rc"], "Rc::new(${receiver})", &["std::rc::Rc"], SnippetScope::Expr),
+        snippet(
+            &[],
+            &["pinbox"],
+            "Box::pin(${receiver})",
+            &["std::boxed::Box"],
+            SnippetScope::Expr,
+        ),
+        snippet(&[], &["ok"], "Ok(${receiver})", &[], SnippetScope::Expr),
+        snippet(&[], &["err"], "Err(${receiver})", &[], SnippetScope::Expr),
+        snippet(&[], &["some"], "Some(${receiver})", &[], SnippetScope::Expr),
+        snippet(
+            &[],
+            &["mutex"],
+            "Mutex::new(${receiver})",
+            &["std::sync::Mutex"],
+            SnippetScope::Expr,
+        ),
+        snippet(
+            &[],
+            &["cell"],
+            "RefCell::new(${receiver})",
+            &["std::cell::RefCell"],
+            SnippetScope::Expr,
+        ),
+        snippet(
+            &["spawn", "thread"],
+            &[],
+            "thread::spawn(move || {\n\t$0\n});",
+            &["std::thread"],
+            SnippetScope::Expr,
+        ),
+        snippet(
+            &["hashmap"],
+            &[],
+            "HashMap::new()",
+            &["std::collections::HashMap"],
+            SnippetScope::Expr,
+        ),
+        snippet(
+            &["hashset"],
+            &[],
+            "HashSet::new()",
+            &["std::collections::HashSet"],
+            SnippetScope::Expr,
+        ),
+        snippet(
+            &["btreemap"],
+            &[],
+            "BTreeMap::new()",
+            &["std::collections::BTreeMap"],
+            SnippetScope::Expr,
+        ),
+        snippet(&["now"], &[], "Instant::now()", &["std::time::Instant"], SnippetScope::Expr),
+        snippet(&["ioresult"], &[], "io::Result<$0>", &["std::io"], SnippetScope::Type),
+        snippet(&["derivedebug"], &[], "#[derive(Debug)]", &[], SnippetScope::Item),
+        snippet(
+            &["testmod"],
+            &[],
+            "#[cfg(test)]\nmod tests {\n\tuse super::*;\n\n\t$0\n}",
+            &[],
+            SnippetScope::Item,
+        ),
+    ]
+}
+
+fn inlay_hints_config() -> InlayHintsConfig {
+    InlayHintsConfig {
+        render_colons: true,
+        type_hints: true,
+        discriminant_hints: DiscriminantHints::Never,
+        parameter_hints: true,
+        chaining_hints: true,
+        adjustment_hints: AdjustmentHints::Never,
+        adjustment_hints_mode: AdjustmentHintsMode::Prefix,
+        adjustment_hints_hide_outside_unsafe: false,
+        closure_return_type_hints: ClosureReturnTypeHints::WithBlock,
+        closure_capture_hints: false,
+        binding_mode_hints: false,
+        implicit_drop_hints: false,
+        lifetime_elision_hints: LifetimeElisionHints::Never,
+        param_names_for_lifetime_elision_hints: false,
+        hide_named_constructor_hints: false,
+        hide_closure_initialization_hints: false,
+        range_exclusive_hints: false,
+        closure_style: hir::ClosureStyle::ImplFn,
+        max_length: Some(25),
+        closing_brace_hints_min_lines: Some(25),
+        fields_to_resolve: InlayFieldsToResolve::empty(),
+    }
+}
+
+fn hover_config() -> HoverConfig {
+    HoverConfig {
+        links_in_hover: true,
+        memory_layout: None,
+        documentation: true,
+        keywords: true,
+        format: HoverDocFormat::Markdown,
+        max_trait_assoc_items_count: None,
+        max_fields_count: Some(5),
+        max_enum_variants_count: Some(5),
+    }
+}
+
+/// Panics inside `load_workspace_at` are hard to debug without a backtrace, so print one for slow
+/// benchmark runs, unless `RUST_BACKTRACE` was set explicitly.
+///
+/// The benchmarks run on parallel test threads, where setting `RUST_BACKTRACE` would race with
+/// the other threads reading the environment. A panic hook is installed instead, once.
+#[allow(clippy::print_stderr)]
+fn enable_load_backtraces() {
+    static INSTALL_HOOK: Once = Once::new();
+    if std::env::var_os("RUN_SLOW_BENCHES").is_none()
+        || std::env::var_os("RUST_BACKTRACE").is_some()
+    {
+        return;
+    }
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            default_hook(info);
+            eprintln!("{}", std::backtrace::Backtrace::force_capture());
+        }));
+    });
+}
+
+/// Progress callback for `load_workspace_info_at` which only reports warnings, like
+/// a toolchain mismatch.
+fn report_load_warnings(progress: LoadProgress) {
+    if let LoadProgress::ToolchainMismatchWarning { .. } = progress {
+        report("workspace loading warning", progress);
+    }
+}
+
+#[allow(clippy::print_stderr)]
+fn report(label: impl fmt::Display, value: impl fmt::Display) {
+    eprintln!("{label}: {value}");
+    MEASUREMENTS.with(|it| {
+        if let Some(measurements) = &mut *it.borrow_mut() {
+            measurements.push((label.to_string(), value.to_string()));
+        }
+    });
+}
+
+/// A `tracing` layer which records the message of every event it sees.
+#[derive(Clone, Default)]
+struct EventCollector(Arc<Mutex<Vec<String>>>);
+
+impl<S: tracing::Subscriber> Layer<S> for EventCollector {
+    fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+        struct Message<'a>(&'a mut String);
+
+        impl Visit for Message<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                if field.name() == "message" {
+                    format_to!(self.0, "{value:?}");
+                }
+            }
+        }
+
+        let mut message = String::new();
+        event.record(&mut Message(&mut message));
+        self.0.lock().unwrap().push(message);
+    }
+}
+
 fn patch(what: &mut String, from: &str, to: &str) -> usize {
     let idx = what.find(from).unwrap();
     *what = what.replacen(from, to, 1);
@@ -235,11 +235,22 @@ pub(crate) fn completion_items(
         completion_item(&mut res, config, line_index, version, &tdpp, max_relevance, item);
     }
 
-    if let Some(limit) = config.completion(None).limit {
+    let completion_config = config.completion(None);
+    if let Some(limit) = completion_config.limit {
         res.sort_by(|item1, item2| item1.sort_text.cmp(&item2.sort_text));
         res.truncate(limit);
     }
 
+    // Several items can share the highest relevance, but clients expect at most one of them to
+    // be preselected.
+    let mut preselect = completion_config.preselect_first_item;
+    for item in &mut res {
+        if item.preselect.is_some() {
+            item.preselect = preselect.then_some(true);
+            preselect = false;
+        }
+    }
+
     res
 }
 
@@ -1694,7 +1705,8 @@ mod tests {
     use expect_test::{expect, Expect};
     use ide::{Analysis, FilePosition};
     use ide_db::source_change::Snippet;
-    use test_utils::extract_offset;
+    use paths::AbsPathBuf;
+    use test_utils::{extract_offset, project_root};
     use triomphe::Arc;
 
     use super::*;
@@ -1763,6 +1775,67 @@ fn bar(_: usize) {}
         assert!(!docs.contains("use crate::bar"));
     }
 
+    fn preselected_completions(preselect_first_item: bool) -> Vec<String> {
+        let text = r#"
+fn add(first: u32, second: u32) -> u32 {
+    $0
+}
+"#;
+
+        let (offset, text) = extract_offset(text);
+        let line_index = LineIndex {
+            index: Arc::new(ide::LineIndex::new(&text)),
+            endings: LineEndings::Unix,
+            encoding: PositionEncoding::Utf8,
+        };
+        let (analysis, file_id) = Analysis::from_single_file(text);
+        let mut config = Config::new(
+            AbsPathBuf::try_from(project_root()).unwrap(),
+            Default::default(),
+            vec![],
+            None,
+        );
+        config
+            .update(serde_json::json!({
+                "completion": { "preselectFirstItem": { "enable": preselect_first_item } },
+            }))
+            .unwrap();
+        let items = analysis
+            .completions(&config.completion(None), FilePosition { file_id, offset }, None)
+            .unwrap()
+            .unwrap();
+
+        // Both locals are equally relevant, so the renderer has to pick one of them.
+        let max_relevance = items.iter().map(|it| it.relevance.score()).max().unwrap();
+        let most_relevant = items
+            .iter()
+            .filter(|it| it.relevance.is_relevant() && it.relevance.score() == max_relevance)
+            .count();
+        assert!(most_relevant > 1);
+
+        let tdpp = lsp_types::TextDocumentPositionParams::new(
+            lsp_types::TextDocumentIdentifier::new(
+                lsp_types::Url::parse("file:///main.rs").unwrap(),
+            ),
+            position(&line_index, offset),
+        );
+        completion_items(&config, &line_index, None, tdpp, items)
+            .into_iter()
+            .filter(|it| it.preselect == Some(true))
+            .map(|it| it.label)
+            .collect()
+    }
+
+    #[test]
+    fn preselects_exactly_one_completion() {
+        assert_eq!(preselected_completions(true).len(), 1);
+    }
+
+    #[test]
+    fn preselects_no_completion_when_disabled() {
+        assert!(preselected_completions(false).is_empty());
+    }
+
     #[track_caller]
     fn check_rendered_snippets(edit: TextEdit, snippets: SnippetEdit, expect: Expect) {
         check_rendered_snippets_in_source(
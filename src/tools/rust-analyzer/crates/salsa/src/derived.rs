@@ -196,6 +196,10 @@ fn purge(&self) {
         self.lru_list.purge();
         *self.slot_map.write() = Default::default();
     }
+
+    fn sweep(&self, revision: Revision) -> usize {
+        self.slot_map.read().values().filter(|slot| slot.sweep(revision)).count()
+    }
 }
 
 impl<Q, MP> LruQueryStorageOps for DerivedStorage<Q, MP>
@@ -426,6 +426,19 @@ pub(super) fn evict(&self) {
         }
     }
 
+    /// Like [`Self::evict`], but only if the value wasn't verified in
+    /// `revision`. Returns whether the value was dropped.
+    pub(super) fn sweep(&self, revision: Revision) -> bool {
+        let mut state = self.state.write();
+        if let QueryState::Memoized(memo) = &mut *state {
+            if memo.verified_at < revision && memo.value.is_some() && !memo.has_untracked_input() {
+                memo.value = None;
+                return true;
+            }
+        }
+        false
+    }
+
     pub(super) fn invalidate(&self, new_revision: Revision) -> Option<Durability> {
         tracing::debug!("Slot::invalidate(new_revision = {:?})", new_revision);
         match &mut *self.state.write() {
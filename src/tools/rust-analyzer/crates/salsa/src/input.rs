@@ -133,6 +133,11 @@ impl<Q> QueryStorageMassOps for InputStorage<Q>
     fn purge(&self) {
         *self.slots.write() = Default::default();
     }
+
+    fn sweep(&self, _revision: Revision) -> usize {
+        // Inputs can't be recomputed, so they are never dropped.
+        0
+    }
 }
 
 impl<Q> InputQueryStorageOps<Q> for InputStorage<Q>
@@ -291,6 +296,11 @@ impl<Q> QueryStorageMassOps for UnitInputStorage<Q>
     fn purge(&self) {
         *self.slot.stamped_value.write() = Default::default();
     }
+
+    fn sweep(&self, _revision: Revision) -> usize {
+        // Inputs can't be recomputed, so they are never dropped.
+        0
+    }
 }
 
 impl<Q> InputQueryStorageOps<Q> for UnitInputStorage<Q>
@@ -331,6 +331,11 @@ impl<Q> QueryStorageMassOps for InternedStorage<Q>
     fn purge(&self) {
         *self.tables.write() = Default::default();
     }
+
+    fn sweep(&self, _revision: Revision) -> usize {
+        // Dropping interned values would change the ids handed out for them.
+        0
+    }
 }
 
 // Workaround for
@@ -453,6 +458,10 @@ impl<Q, IQ> QueryStorageMassOps for LookupInternedStorage<Q, IQ>
     IQ: Query<Key = Q::Value, Value = Q::Key>,
 {
     fn purge(&self) {}
+
+    fn sweep(&self, _revision: Revision) -> usize {
+        0
+    }
 }
 
 impl<K> Slot<K> {
@@ -69,6 +69,10 @@ fn fmt_index(
 /// query, unlike `QueryStorageOps`).
 pub trait QueryStorageMassOps {
     fn purge(&self);
+
+    /// Drops the memoized values which weren't verified in `revision`,
+    /// returning how many were dropped.
+    fn sweep(&self, revision: Revision) -> usize;
 }
 
 pub trait DatabaseKey: Clone + Debug + Eq + Hash {}
@@ -0,0 +1,57 @@
+//! Test that sweeping drops exactly the values which weren't verified in the
+//! current revision.
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use salsa::{plumbing::DatabaseOps, Database as _};
+
+#[salsa::query_group(QueryGroupStorage)]
+trait QueryGroup: salsa::Database {
+    #[salsa::input]
+    fn input(&self, x: u32) -> u32;
+    fn double(&self, x: u32) -> u32;
+}
+
+static EXECUTIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn double(db: &dyn QueryGroup, x: u32) -> u32 {
+    EXECUTIONS.fetch_add(1, Ordering::SeqCst);
+    db.input(x) * 2
+}
+
+#[salsa::database(QueryGroupStorage)]
+#[derive(Default)]
+struct Database {
+    storage: salsa::Storage<Self>,
+}
+
+impl salsa::Database for Database {}
+
+fn sweep(db: &Database) -> usize {
+    let revision = db.salsa_runtime().current_revision();
+    let mut swept = 0;
+    db.for_each_query(&mut |storage| swept += storage.sweep(revision));
+    swept
+}
+
+#[test]
+fn sweep_works() {
+    let mut db = Database::default();
+    db.set_input(0, 1);
+    db.set_input(1, 2);
+    assert_eq!(db.double(0), 2);
+    assert_eq!(db.double(1), 4);
+    assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 2);
+
+    // Everything was verified in the current revision.
+    assert_eq!(sweep(&db), 0);
+
+    db.set_input(2, 3);
+    assert_eq!(db.double(0), 2);
+    assert_eq!(sweep(&db), 1);
+    assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 2);
+
+    // `double(1)` was swept and has to be recomputed, `double(0)` is still memoized.
+    assert_eq!(db.double(1), 4);
+    assert_eq!(db.double(0), 2);
+    assert_eq!(EXECUTIONS.load(Ordering::SeqCst), 3);
+}